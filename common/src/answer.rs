@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// The answer to one part of a puzzle. Serialized as a JSON number or string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Unsigned: u8, u16, u32, u64, u128, usize);
+impl_from!(Signed: i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let answers = [
+            Answer::from(42usize),
+            Answer::from(-3i64),
+            Answer::from("abc"),
+        ];
+        assert_eq!(serde_json::to_string(&answers).unwrap(), r#"[42,-3,"abc"]"#);
+        assert_eq!(answers[1].to_string(), "-3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from(u128::MAX)).unwrap(),
+            u128::MAX.to_string()
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Properties of the puzzle input which a solver relies on without them being stated in the
+//! puzzle, checked before solving instead of silently giving a wrong answer.
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: &'static str,
+    pub holds: bool,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, holds: bool) -> Self {
+        Assumption { description, holds }
+    }
+}
+
+impl Display for Assumption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = if self.holds { "holds" } else { "FAILS" };
+        write!(f, "{status}: {}", self.description)
+    }
+}
+
+/// The assumptions which do not hold.
+pub fn failed(assumptions: Vec<Assumption>) -> Vec<Assumption> {
+    assumptions
+        .into_iter()
+        .filter(|assumption| !assumption.holds)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_assumptions() {
+        let assumptions = vec![
+            Assumption::new("The map is square.", true),
+            Assumption::new("The start is in the centre.", false),
+        ];
+        assert_eq!(assumptions[0].to_string(), "holds: The map is square.");
+        assert_eq!(
+            failed(assumptions),
+            [Assumption::new("The start is in the centre.", false)]
+        );
+    }
+}
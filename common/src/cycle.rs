@@ -0,0 +1,75 @@
+//! Detection of repeating states in sequences which would be too long to follow to the end.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// A sequence in which every state determines the next one, so that it eventually repeats:
+/// after the first `tail` states, the same `period` states follow over and over again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    states: Vec<S>,
+    tail: usize,
+}
+
+impl<S: Clone + Eq + Hash> Cycle<S> {
+    /// Follows the sequence from `start` until a state repeats, remembering all states by hash.
+    pub fn find(start: S, mut next: impl FnMut(&S) -> S) -> Self {
+        let mut indices = HashMap::from([(start.clone(), 0)]);
+        let mut states = vec![start];
+        loop {
+            let state = next(&states[states.len() - 1]);
+            if let Some(&tail) = indices.get(&state) {
+                return Cycle { states, tail };
+            }
+            indices.insert(state.clone(), states.len());
+            states.push(state);
+        }
+    }
+}
+
+impl<S> Cycle<S> {
+    /// The number of states before the first one which repeats.
+    pub fn tail(&self) -> usize {
+        self.tail
+    }
+
+    /// The number of states that keep repeating.
+    pub fn period(&self) -> usize {
+        self.states.len() - self.tail
+    }
+
+    /// The state after `n` steps from the start.
+    pub fn nth(&self, n: usize) -> &S {
+        if n < self.tail {
+            &self.states[n]
+        } else {
+            &self.states[self.tail + (n - self.tail) % self.period()]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle() {
+        // 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4, ...
+        let next = |n: &u32| n * 2 % 100;
+        let cycle = Cycle::find(2, next);
+        assert_eq!((cycle.tail(), cycle.period()), (1, 20));
+
+        let mut state = 2;
+        for n in 0..50 {
+            assert_eq!(*cycle.nth(n), state);
+            state = next(&state);
+        }
+        assert_eq!(*cycle.nth(1_000_000_000), 52);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let cycle = Cycle::find(0, |n: &u8| (n + 1).min(3));
+        assert_eq!((cycle.tail(), cycle.period()), (3, 1));
+        assert_eq!(*cycle.nth(usize::MAX), 3);
+    }
+}
@@ -0,0 +1,170 @@
+use std::{fmt::Display, process, str::FromStr};
+
+use nom::{error::Error, Finish, IResult};
+
+/// A problem in the puzzle input, located by line and column (both starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, up to the end of its line.
+    pub text: String,
+    /// What was expected instead of `text`.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error at the start of `text`, which has to be a slice of `input`.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let before = &input[..offset(input, text)];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+        ParseError {
+            line,
+            column,
+            text: text.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error behind the last character of `input`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    /// Converts an error found in `part`, a slice of `input`, to a position within `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let start = Self::at(input, part, "");
+        let column = match self.line {
+            1 => start.column + self.column - 1,
+            _ => self.column,
+        };
+        ParseError {
+            line: start.line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    /// Converts the error of a nom parser that was applied to `input`.
+    pub fn from_nom(input: &str, error: Error<&str>) -> Self {
+        let expected = error.code.description().to_lowercase();
+        Self::at(input, error.input, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        match self.text.as_str() {
+            "" => write!(f, ", found end of line"),
+            text => write!(f, ", found \"{text}\""),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `text` within `input`. Falls back to searching, if `text` is no slice of `input`.
+fn offset(input: &str, text: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = text.as_ptr() as usize;
+    if (start..=start + input.len()).contains(&position) {
+        position - start
+    } else {
+        input.find(text).unwrap_or(0)
+    }
+}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "a number"))
+}
+
+/// Parses every line of `input`, reporting errors with their position within `input`.
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|error: ParseError| error.within(input, line))
+        })
+        .collect()
+}
+
+/// Finishes a nom parser that was applied to `input`. Everything but trailing whitespace has
+/// to be consumed.
+pub fn complete<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    let (rest, value) = result
+        .finish()
+        .map_err(|error| ParseError::from_nom(input, error))?;
+    match rest.trim_end() {
+        "" => Ok(value),
+        rest => Err(ParseError::at(input, rest, "end of input")),
+    }
+}
+
+/// Parses the input for the binary of a day. Exits with an error message if this fails.
+pub fn parse_or_exit<'a, T>(
+    input: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+) -> T {
+    match parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Invalid input at {error}.");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "abc\ndef\nghi";
+        let error = ParseError::at(input, &input[5..], "x");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "ef");
+
+        let error = ParseError::at_end(input, "x");
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected x, found end of line"
+        );
+    }
+
+    #[test]
+    fn nested_position() {
+        let input = "1 2\n3 x 5";
+        let error = lines::<Numbers>(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[derive(Debug)]
+    struct Numbers;
+
+    impl FromStr for Numbers {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            for number in s.split(' ') {
+                super::number::<u32>(s, number)?;
+            }
+            Ok(Numbers)
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Directions and coordinates on a plane, with `y` growing downwards like the rows of a map.
+
+use std::{fmt::Debug, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The direction after a 90° turn counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction after a 90° turn clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.reverse().turn_left()
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+/// A primitive integer, which can be used for the components of a [`Coordinate`].
+pub trait Scalar: Copy + Ord + Default + Hash + Debug {
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The absolute difference, which fits into an `u64` for all implementors.
+    fn distance(self, other: Self) -> u64;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn distance(self, other: Self) -> u64 {
+                    self.abs_diff(other) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i16, i32, i64, isize, u16, u32, u64, usize);
+
+/// A point on the plane: `x` is the column, `y` the row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinate<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coordinate<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Coordinate { x, y }
+    }
+}
+
+impl<T: Scalar> Coordinate<T> {
+    /// The adjacent coordinate in `direction`, unless it does not fit into `T`.
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, T::ONE)
+    }
+
+    /// The coordinate `distance` steps away in `direction`, unless it does not fit into `T`.
+    pub fn step_by(&self, direction: Direction, distance: T) -> Option<Self> {
+        let coordinate = match direction {
+            Direction::Up => Coordinate::new(self.x, self.y.checked_sub(distance)?),
+            Direction::Down => Coordinate::new(self.x, self.y.checked_add(distance)?),
+            Direction::Left => Coordinate::new(self.x.checked_sub(distance)?, self.y),
+            Direction::Right => Coordinate::new(self.x.checked_add(distance)?, self.y),
+        };
+        Some(coordinate)
+    }
+
+    /// The number of steps from `self` to `other`, unless it does not fit into an `u64`.
+    pub fn manhattan_distance(&self, other: &Self) -> Option<u64> {
+        self.x
+            .distance(other.x)
+            .checked_add(self.y.distance(other.y))
+    }
+
+    /// Converts both components to `U`, unless one of them does not fit.
+    pub fn try_convert<U: TryFrom<T>>(self) -> Option<Coordinate<U>> {
+        Some(Coordinate {
+            x: U::try_from(self.x).ok()?,
+            y: U::try_from(self.y).ok()?,
+        })
+    }
+}
+
+impl<T> From<(T, T)> for Coordinate<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Coordinate { x, y }
+    }
+}
+
+impl<T> From<Coordinate<T>> for (T, T) {
+    fn from(coordinate: Coordinate<T>) -> Self {
+        (coordinate.x, coordinate.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+            assert_ne!(
+                direction.is_horizontal(),
+                direction.turn_right().is_horizontal()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn checked_steps() {
+        let origin = Coordinate::<usize>::new(0, 0);
+        assert_eq!(origin.step(Direction::Up), None);
+        assert_eq!(origin.step(Direction::Down), Some(Coordinate::new(0, 1)));
+
+        let corner = Coordinate::new(i16::MAX, i16::MIN);
+        assert_eq!(corner.step(Direction::Right), None);
+        assert_eq!(corner.step(Direction::Up), None);
+        assert_eq!(
+            corner.step_by(Direction::Left, 7),
+            Some(Coordinate::new(i16::MAX - 7, i16::MIN))
+        );
+    }
+
+    #[test]
+    fn distances_and_conversions() {
+        let start = Coordinate::new(-3i64, 4);
+        let end = Coordinate::new(2i64, -1);
+        assert_eq!(start.manhattan_distance(&end), Some(10));
+        assert_eq!(
+            Coordinate::new(i64::MIN, 0).manhattan_distance(&Coordinate::new(i64::MAX, 0)),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            Coordinate::new(i64::MIN, 0).manhattan_distance(&Coordinate::new(i64::MAX, 1)),
+            None
+        );
+
+        assert_eq!(start.try_convert::<usize>(), None);
+        assert_eq!(end.try_convert::<i16>(), Some(Coordinate::new(2, -1)));
+        assert_eq!(<(i64, i64)>::from(start), (-3, 4));
+    }
+}
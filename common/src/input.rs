@@ -0,0 +1,180 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{self, Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+/// Where the puzzle input is read from.
+///
+/// Parsed from a command line argument: `-` selects stdin, anything containing a path
+/// separator is a file path, and everything else names a file in the `data` folder of the day
+/// (e.g. `demo_input2` for `data/demo_input2.txt`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Data(String),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Data("input".to_string())
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = if s == "-" {
+            Source::Stdin
+        } else if s.contains(path::is_separator) {
+            Source::File(PathBuf::from(s))
+        } else {
+            Source::Data(s.trim_end_matches(".txt").to_string())
+        };
+        Ok(source)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Data(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    MissingData {
+        path: PathBuf,
+        available: Vec<String>,
+    },
+    Io {
+        source: Source,
+        error: io::Error,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingData { path, available } => {
+                write!(f, "There is no data file {}.", path.display())?;
+                if !available.is_empty() {
+                    write!(f, " Available are: {}.", available.join(", "))?;
+                }
+                Ok(())
+            }
+            Error::Io { source, error } => write!(f, "Cannot read input {source}: {error}."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Source {
+    /// Reads the input. Data files are looked up in the `data` folder of `day_directory`.
+    pub fn read(&self, day_directory: &Path) -> Result<String, Error> {
+        let io_error = |error| Error::Io {
+            source: self.clone(),
+            error,
+        };
+        match self {
+            Source::File(path) => fs::read_to_string(path).map_err(io_error),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(io_error)?;
+                Ok(input)
+            }
+            Source::Data(name) => {
+                let data_directory = day_directory.join("data");
+                let path = data_directory.join(format!("{name}.txt"));
+                if !path.is_file() {
+                    return Err(Error::MissingData {
+                        path,
+                        available: data_files(&data_directory),
+                    });
+                }
+                fs::read_to_string(path).map_err(io_error)
+            }
+        }
+    }
+}
+
+/// Names of all data files in a `data` folder, sorted.
+pub fn data_files(data_directory: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(data_directory)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            match path.extension() {
+                Some(extension) if extension == "txt" => {
+                    Some(path.file_stem()?.to_string_lossy().into_owned())
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Reads the input selected by the first command line argument, `input` by default.
+/// Exits with an error message if the input cannot be read.
+pub fn from_args(day_directory: impl AsRef<Path>) -> String {
+    let source = match std::env::args().nth(1) {
+        Some(argument) => Source::from_str(&argument).unwrap(),
+        None => Source::default(),
+    };
+
+    match source.read(day_directory.as_ref()) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sources() {
+        let sources = [
+            ("-", Source::Stdin),
+            ("input", Source::Data("input".to_string())),
+            ("demo_input2.txt", Source::Data("demo_input2".to_string())),
+            ("./input.txt", Source::File(PathBuf::from("./input.txt"))),
+            ("/tmp/x", Source::File(PathBuf::from("/tmp/x"))),
+        ];
+        for (argument, expected) in sources {
+            assert_eq!(Source::from_str(argument).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn missing_data_file() {
+        let day_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day_01");
+        let error = Source::Data("demo_input3".to_string())
+            .read(&day_directory)
+            .unwrap_err();
+
+        match error {
+            Error::MissingData { available, .. } => {
+                assert_eq!(available, ["demo_input", "demo_input_2", "input"])
+            }
+            _ => panic!("unexpected error {error}"),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Functionality shared by the solutions of all days.
+
+pub mod answer;
+pub mod assumption;
+pub mod cycle;
+pub mod error;
+pub mod geometry;
+pub mod input;
+pub mod polygon;
+pub mod random;
+pub mod solution;
+pub mod total;
@@ -0,0 +1,235 @@
+//! Polygons whose corners lie on the integer lattice, like loops drawn on a map.
+
+use crate::geometry::Coordinate;
+
+type Point = Coordinate<i64>;
+
+/// The order in which the corners run, as seen on a map with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+}
+
+/// A closed polygon, given by its corners in order. The last corner connects to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// A repetition of the first corner at the end is dropped.
+    pub fn new(mut vertices: Vec<Point>) -> Self {
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area by the shoelace formula. Positive if the corners run clockwise.
+    pub fn twice_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum()
+    }
+
+    /// `None` if the polygon has no area.
+    pub fn orientation(&self) -> Option<Orientation> {
+        match self.twice_signed_area().signum() {
+            1 => Some(Orientation::Clockwise),
+            -1 => Some(Orientation::Counterclockwise),
+            _ => None,
+        }
+    }
+
+    /// The number of lattice points on the edges.
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside, by Pick's theorem. Only meaningful for
+    /// simple polygons.
+    pub fn interior_points(&self) -> u64 {
+        let twice_area = self.twice_signed_area().unsigned_abs();
+        let interior = (twice_area + 2).saturating_sub(self.boundary_points() as u128) / 2;
+        interior
+            .try_into()
+            .expect("Polygon has more interior points than fit into u64.")
+    }
+
+    /// Whether `point` lies strictly inside, counting the edges crossed by a ray to the left of
+    /// it. Points on the edges are not inside.
+    pub fn contains(&self, point: Point) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if on_segment(a, b, point) {
+                return false;
+            }
+            if (a.y > point.y) != (b.y > point.y) {
+                // The edge crosses the row of the point, left of it if the point is on the
+                // right of the edge seen from its lower end.
+                let (low, high) = if a.y < b.y { (a, b) } else { (b, a) };
+                if turn(low, high, point) < 0 {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Whether no two edges touch, except for consecutive edges in their common corner.
+    pub fn is_simple(&self) -> bool {
+        let edges = self.edges().collect::<Vec<_>>();
+        if edges.len() < 3 || edges.iter().any(|(a, b)| a == b) {
+            return false;
+        }
+        for (i, &(a, b)) in edges.iter().enumerate() {
+            for (j, &(c, d)) in edges.iter().enumerate().skip(i + 1) {
+                let touching = if j == i + 1 {
+                    on_segment(b, a, d) || on_segment(c, d, a)
+                } else if i == 0 && j == edges.len() - 1 {
+                    on_segment(a, b, c) || on_segment(d, c, b)
+                } else {
+                    segments_touch(a, b, c, d)
+                };
+                if touching {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl FromIterator<Point> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Polygon::new(iter.into_iter().collect())
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The sign of the turn from `a` via `b` to `c`.
+fn turn(a: Point, b: Point, c: Point) -> i128 {
+    let cross = (b.x as i128 - a.x as i128) * (c.y as i128 - a.y as i128)
+        - (b.y as i128 - a.y as i128) * (c.x as i128 - a.x as i128);
+    cross.signum()
+}
+
+/// Whether `point` lies on the segment from `a` to `b`.
+fn on_segment(a: Point, b: Point, point: Point) -> bool {
+    turn(a, b, point) == 0
+        && a.x.min(b.x) <= point.x
+        && point.x <= a.x.max(b.x)
+        && a.y.min(b.y) <= point.y
+        && point.y <= a.y.max(b.y)
+}
+
+fn segments_touch(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let crossing = turn(a, b, c) * turn(a, b, d) < 0 && turn(c, d, a) * turn(c, d, b) < 0;
+    crossing
+        || on_segment(a, b, c)
+        || on_segment(a, b, d)
+        || on_segment(c, d, a)
+        || on_segment(c, d, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        vertices.iter().copied().map(Point::from).collect()
+    }
+
+    #[test]
+    fn area_and_points() {
+        // Clockwise on a map: right, down, left.
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)]);
+        assert_eq!(square.vertices().len(), 4);
+        assert_eq!(square.twice_signed_area(), 32);
+        assert_eq!(square.orientation(), Some(Orientation::Clockwise));
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+
+        let triangle = polygon(&[(0, 0), (0, 3), (6, 0)]);
+        assert_eq!(triangle.twice_signed_area(), -18);
+        assert_eq!(triangle.orientation(), Some(Orientation::Counterclockwise));
+        assert_eq!(triangle.boundary_points(), 3 + 6 + 3);
+        assert_eq!(triangle.interior_points(), 4);
+
+        let line = polygon(&[(0, 0), (5, 0)]);
+        assert_eq!(line.orientation(), None);
+        assert_eq!(line.interior_points(), 0);
+    }
+
+    #[test]
+    fn inside_points() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert!(square.contains(Point::new(1, 1)));
+        assert!(square.contains(Point::new(3, 2)));
+        assert!(!square.contains(Point::new(0, 2)));
+        assert!(!square.contains(Point::new(4, 4)));
+        assert!(!square.contains(Point::new(5, 2)));
+
+        // A U shape, whose notch is outside.
+        let u = polygon(&[
+            (0, 0),
+            (2, 0),
+            (2, 4),
+            (4, 4),
+            (4, 0),
+            (6, 0),
+            (6, 6),
+            (0, 6),
+        ]);
+        let inside = (-1..=7)
+            .flat_map(|y| (-1..=7).map(move |x| Point::new(x, y)))
+            .filter(|point| u.contains(*point))
+            .count() as u64;
+        assert_eq!(inside, u.interior_points());
+        assert!(u.contains(Point::new(1, 2)) && u.contains(Point::new(3, 5)));
+        assert!(!u.contains(Point::new(3, 2)) && !u.contains(Point::new(3, 4)));
+    }
+
+    #[test]
+    fn self_intersections() {
+        assert!(polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]).is_simple());
+        assert!(polygon(&[(0, 0), (2, 0), (2, 2), (1, 2), (1, 1), (0, 1)]).is_simple());
+
+        // A bow tie, whose edges cross.
+        assert!(!polygon(&[(0, 0), (2, 2), (2, 0), (0, 2)]).is_simple());
+        // Two squares touching in a corner.
+        assert!(!polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 1),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (1, 1),
+            (0, 1)
+        ])
+        .is_simple());
+        // An edge that turns back onto the previous one.
+        assert!(!polygon(&[(0, 0), (3, 0), (1, 0), (1, 1)]).is_simple());
+        assert!(!polygon(&[(0, 0), (1, 0)]).is_simple());
+    }
+}
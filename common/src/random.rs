@@ -0,0 +1,131 @@
+//! A small seedable random number generator for generating puzzle inputs reproducibly.
+
+use std::{
+    fmt::Display,
+    ops::{Range, RangeInclusive},
+};
+
+/// The SplitMix64 generator: the same seed always gives the same numbers, on every platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0..bound`. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Cannot choose from an empty range.");
+        // Rejects the numbers which would make the remainders uneven.
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let number = self.next_u64();
+            if number < zone {
+                return number % bound;
+            }
+        }
+    }
+
+    /// A number from `range`. Panics if the range is empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(
+            range.start < range.end,
+            "Cannot choose from an empty range."
+        );
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add_unsigned(self.below(width))
+    }
+
+    /// An index into something of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with the probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// One of `items`. Panics if there are none.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A size which a generator cannot make an input of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeError {
+    pub size: usize,
+    pub supported: RangeInclusive<usize>,
+}
+
+impl SizeError {
+    /// `size`, if it is one of the `supported` ones.
+    pub fn check(size: usize, supported: RangeInclusive<usize>) -> Result<usize, SizeError> {
+        match supported.contains(&size) {
+            true => Ok(size),
+            false => Err(SizeError { size, supported }),
+        }
+    }
+}
+
+impl Display for SizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cannot generate an input of size {}, only of sizes {} to {}",
+            self.size,
+            self.supported.start(),
+            self.supported.end()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let numbers = |seed| {
+            let mut random = Random::new(seed);
+            (0..5).map(|_| random.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        // The reference output of SplitMix64 for the seed 0.
+        assert_eq!(numbers(0)[0], 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut random = Random::new(1);
+        for _ in 0..1000 {
+            assert!(random.below(3) < 3);
+            assert!((-5..5).contains(&random.range(-5..5)));
+        }
+        assert_eq!(random.range(i64::MIN..i64::MIN + 1), i64::MIN);
+
+        let mut items = [1, 2, 3, 4, 5];
+        random.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,307 @@
+//! A common interface to the solvers of all days, and a registry through which the runner
+//! finds them.
+
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    assumption::{self, Assumption},
+    error::ParseError,
+    total::Overflow,
+};
+
+/// The solver of a day, which parses the input once and answers the parts from it.
+pub trait Solution {
+    /// The input as the parts need it, possibly borrowing from the text.
+    type Parsed<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError>;
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow>;
+
+    /// `None` for days with only one part.
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>>;
+
+    /// What `part` relies on beyond a valid input, like properties only the real input has.
+    fn assumptions(&self, _parsed: &Self::Parsed<'_>, _part: u8) -> Vec<Assumption> {
+        Vec::new()
+    }
+}
+
+/// Why a solver gave no answer for an input.
+#[derive(Debug)]
+pub enum Failure {
+    Parse(ParseError),
+    /// The assumptions about the input which do not hold.
+    Assumptions(Vec<Assumption>),
+    /// An integer overflowed while solving. Outside of the checked arithmetic of the answers,
+    /// this is only detected in builds with overflow checks, like the `checked` profile.
+    Overflow(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Parse(error) => write!(f, "Invalid input at {error}."),
+            Failure::Assumptions(failed) => {
+                write!(f, "The input does not fit the solver:")?;
+                failed
+                    .iter()
+                    .try_for_each(|assumption| write!(f, " {}", assumption.description))
+            }
+            Failure::Overflow(message) => write!(f, "An integer overflowed: {message}."),
+        }
+    }
+}
+
+/// The answer of one part, with the time taken to parse the input and to solve the part.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// A [`Solution`] with its parsed input hidden, so that the solutions of all days fit in one
+/// registry.
+pub trait Solver: Send + Sync {
+    /// Parses `input`, checks the assumptions and solves `part`. `None` if there is no such
+    /// part. The time to check the assumptions counts for neither step. Panics other than
+    /// overflows are passed on.
+    fn run(&self, part: u8, input: &str) -> Option<Result<Run, Failure>>;
+
+    /// Parses `input` and checks all assumptions of `part`, whether they hold or not.
+    fn check(&self, part: u8, input: &str) -> Result<Vec<Assumption>, ParseError>;
+}
+
+impl<S: Solution + Send + Sync> Solver for S {
+    fn run(&self, part: u8, input: &str) -> Option<Result<Run, Failure>> {
+        if !(1..=2).contains(&part) {
+            return None;
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| solve(self, part, input))) {
+            Ok(run) => run,
+            Err(payload) => match overflow(payload.as_ref()) {
+                Some(message) => Some(Err(Failure::Overflow(message.to_string()))),
+                None => panic::resume_unwind(payload),
+            },
+        }
+    }
+
+    fn check(&self, part: u8, input: &str) -> Result<Vec<Assumption>, ParseError> {
+        let parsed = self.parse(input)?;
+        Ok(self.assumptions(&parsed, part))
+    }
+}
+
+fn solve<S: Solution>(solution: &S, part: u8, input: &str) -> Option<Result<Run, Failure>> {
+    let start = Instant::now();
+    let parsed = match solution.parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => return Some(Err(Failure::Parse(error))),
+    };
+    let parse = start.elapsed();
+
+    let failed = assumption::failed(solution.assumptions(&parsed, part));
+    if !failed.is_empty() {
+        return Some(Err(Failure::Assumptions(failed)));
+    }
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part1(&parsed),
+        _ => solution.part2(&parsed)?,
+    };
+    Some(match answer {
+        Ok(answer) => Ok(Run {
+            answer,
+            parse,
+            solve: start.elapsed(),
+        }),
+        Err(overflow) => Err(Failure::Overflow(overflow.to_string())),
+    })
+}
+
+/// The message of a panic caused by an arithmetic overflow, like "attempt to add with
+/// overflow".
+fn overflow(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .filter(|message| message.starts_with("attempt to") && message.ends_with("with overflow"))
+}
+
+/// Keeps the panic hook from printing overflows, which [`Solver::run`] reports as failures.
+/// Other panics are printed as before.
+pub fn quiet_overflows() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if overflow(info.payload()).is_none() {
+            hook(info);
+        }
+    }));
+}
+
+/// A solver of a day under its name.
+pub struct Entry {
+    pub day: u8,
+    pub name: &'static str,
+    pub solver: Box<dyn Solver>,
+}
+
+/// The solvers of all days in the order they were registered, possibly several for one day.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a solver for `day`. The first one registered for a day is its default.
+    pub fn register(&mut self, day: u8, name: &'static str, solution: impl Solver + 'static) {
+        self.entries.push(Entry {
+            day,
+            name,
+            solver: Box::new(solution),
+        });
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// The solvers of `day`, the default one first.
+    pub fn day(&self, day: u8) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.day == day)
+    }
+
+    /// The solver of `day` called `name`, or the default one without a name.
+    pub fn get(&self, day: u8, name: Option<&str>) -> Option<&Entry> {
+        self.day(day)
+            .find(|entry| name.is_none_or(|name| entry.name == name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint;
+
+    use super::*;
+    use crate::total;
+
+    struct Words;
+
+    impl Solution for Words {
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+            match input.is_empty() {
+                true => Err(ParseError::at_end(input, "a word")),
+                false => Ok(input.split_whitespace().collect()),
+            }
+        }
+
+        fn part1(&self, words: &Vec<&str>) -> Result<Answer, Overflow> {
+            Ok(words.len().into())
+        }
+
+        fn part2(&self, _: &Vec<&str>) -> Option<Result<Answer, Overflow>> {
+            None
+        }
+
+        fn assumptions(&self, words: &Vec<&str>, _: u8) -> Vec<Assumption> {
+            vec![Assumption::new(
+                "There are no more than three words.",
+                words.len() <= 3,
+            )]
+        }
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = Registry::new();
+        registry.register(3, "words", Words);
+        registry.register(3, "other", Words);
+        registry.register(4, "words", Words);
+
+        assert_eq!(registry.iter().count(), 3);
+        assert_eq!(registry.day(3).count(), 2);
+        assert_eq!(registry.get(3, None).unwrap().name, "words");
+        assert_eq!(registry.get(3, Some("other")).unwrap().name, "other");
+        assert!(registry.get(3, Some("none")).is_none());
+        assert!(registry.get(5, None).is_none());
+
+        let solver = &registry.get(4, None).unwrap().solver;
+        assert_eq!(
+            solver.run(1, "a b c").unwrap().unwrap().answer,
+            3usize.into()
+        );
+        assert!(matches!(solver.run(1, "").unwrap(), Err(Failure::Parse(_))));
+        let Err(Failure::Assumptions(failed)) = solver.run(1, "a b c d").unwrap() else {
+            panic!("The assumption holds for four words.");
+        };
+        assert_eq!(failed.len(), 1);
+        assert!(solver
+            .check(1, "a b c d")
+            .unwrap()
+            .iter()
+            .all(|assumption| !assumption.holds));
+        assert!(solver.run(2, "a b c").is_none());
+        assert!(solver.run(3, "a b c").is_none());
+    }
+
+    /// Multiplies the lengths of the words in a byte, checked in part 1 and unchecked in part 2.
+    struct Product;
+
+    impl Solution for Product {
+        type Parsed<'a> = Vec<u8>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u8>, ParseError> {
+            Ok(input
+                .split_whitespace()
+                .map(|word| word.len() as u8)
+                .collect())
+        }
+
+        fn part1(&self, lengths: &Vec<u8>) -> Result<Answer, Overflow> {
+            total::product(lengths.iter().copied()).map(Answer::from)
+        }
+
+        fn part2(&self, lengths: &Vec<u8>) -> Option<Result<Answer, Overflow>> {
+            Some(Ok(lengths.iter().product::<u8>().into()))
+        }
+    }
+
+    /// Whether this build panics on overflows, like debug builds and the `checked` profile.
+    fn overflow_checks() -> bool {
+        panic::catch_unwind(|| hint::black_box(u8::MAX) + 1).is_err()
+    }
+
+    #[test]
+    fn overflow() {
+        let run = Product.run(1, "abcd efgh ijklmnop").unwrap();
+        assert_eq!(run.unwrap().answer, 128u8.into());
+        let Err(failure) = Product.run(1, "abcd efgh ijklmnopqrstuvwx").unwrap() else {
+            panic!("The product of the lengths fits in a byte.");
+        };
+        assert_eq!(
+            failure.to_string(),
+            "An integer overflowed: attempt to multiply with overflow."
+        );
+
+        let run = Product.run(2, "abcd efgh ijklmnopqrstuvwx").unwrap();
+        if overflow_checks() {
+            assert!(matches!(run, Err(Failure::Overflow(_))));
+        } else {
+            assert_eq!(run.unwrap().answer, 0u8.into());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! The integer types in which the solvers accumulate their answers, and checked arithmetic on
+//! them which reports an overflow instead of wrapping around. The `wide` feature makes the
+//! totals 128 bits wide instead of 64.
+
+use std::{fmt::Display, process};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+
+#[cfg(not(feature = "wide"))]
+pub type Total = u64;
+#[cfg(feature = "wide")]
+pub type Total = u128;
+
+#[cfg(not(feature = "wide"))]
+pub type SignedTotal = i64;
+#[cfg(feature = "wide")]
+pub type SignedTotal = i128;
+
+/// An arithmetic operation whose result does not fit into its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    /// The operation, like "add" or "multiply".
+    pub operation: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Worded like the panics of builds with overflow checks.
+        write!(f, "attempt to {} with overflow", self.operation)
+    }
+}
+
+pub fn add<T: CheckedAdd>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_add(&b).ok_or(Overflow { operation: "add" })
+}
+
+pub fn subtract<T: CheckedSub>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_sub(&b).ok_or(Overflow {
+        operation: "subtract",
+    })
+}
+
+pub fn multiply<T: CheckedMul>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_mul(&b).ok_or(Overflow {
+        operation: "multiply",
+    })
+}
+
+pub fn power<T: CheckedMul + One + Clone>(base: T, exponent: u32) -> Result<T, Overflow> {
+    (0..exponent).try_fold(T::one(), |power, _| multiply(power, base.clone()))
+}
+
+pub fn sum<T: CheckedAdd + Zero>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::zero(), add)
+}
+
+pub fn product<T: CheckedMul + One>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::one(), multiply)
+}
+
+/// The answer for the binary of a day. Exits with an error message if it overflowed.
+pub fn or_exit<T>(answer: Result<T, Overflow>) -> T {
+    match answer {
+        Ok(answer) => answer,
+        Err(overflow) => {
+            eprintln!("An integer overflowed: {overflow}.");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked() {
+        assert_eq!(sum([1u8, 2, 3]), Ok(6));
+        assert_eq!(product([2u8, 3, 4]), Ok(24));
+        assert_eq!(power(2u8, 7), Ok(128));
+        assert_eq!(power(2u8, 0), Ok(1));
+        assert_eq!(subtract(-100i8, 28), Ok(-128));
+
+        assert_eq!(sum([200u8, 56]), Err(Overflow { operation: "add" }));
+        assert_eq!(
+            power(2u8, 8),
+            Err(Overflow {
+                operation: "multiply"
+            })
+        );
+        assert_eq!(
+            subtract(-100i8, 29).unwrap_err().to_string(),
+            "attempt to subtract with overflow"
+        );
+    }
+
+    #[test]
+    fn totals() {
+        let width = if cfg!(feature = "wide") { 128 } else { 64 };
+        assert_eq!(Total::BITS, width);
+        assert_eq!(SignedTotal::BITS, width);
+    }
+}
@@ -0,0 +1,117 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::{self, Overflow, Total},
+};
+
+const WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+fn get_first_digit(line: &str, words: &[(&str, u32)]) -> Option<u32> {
+    for &(word, value) in words {
+        if line.starts_with(word) {
+            return Some(value);
+        }
+    }
+    if line.chars().next().unwrap().is_ascii_digit() {
+        line.chars().next().unwrap().to_digit(10)
+    } else {
+        get_first_digit(&line[1..], words)
+    }
+}
+
+fn get_last_digit(line: &str, words: &[(&str, u32)]) -> Option<u32> {
+    for &(word, value) in words {
+        if line.ends_with(word) {
+            return Some(value);
+        }
+    }
+    if line.chars().last().unwrap().is_ascii_digit() {
+        line.chars().last().unwrap().to_digit(10)
+    } else {
+        get_last_digit(&line[..line.len() - 1], words)
+    }
+}
+
+fn calibration_sum(lines: &[&str], words: &[(&str, u32)]) -> Result<Total, Overflow> {
+    total::sum(lines.iter().map(|line| {
+        let first_digit = get_first_digit(line, words).unwrap();
+        let last_digit = get_last_digit(line, words).unwrap();
+
+        Total::from(first_digit * 10 + last_digit)
+    }))
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .lines()
+        .map(
+            |line| match line.find(|c: char| !c.is_ascii_alphanumeric()) {
+                Some(position) => Err(ParseError::at(
+                    input,
+                    &line[position..],
+                    "a letter or digit",
+                )),
+                None => Ok(line),
+            },
+        )
+        .collect()
+}
+
+pub fn part1(lines: &[&str]) -> Result<Total, Overflow> {
+    calibration_sum(lines, &[])
+}
+
+pub fn part2(lines: &[&str]) -> Result<Total, Overflow> {
+    calibration_sum(lines, &WORDS)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(part2(parsed).map(Answer::from))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(1, "default", Day01);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let lines = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&lines), Ok(142));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let lines = parse(include_str!("../data/demo_input_2.txt")).unwrap();
+        assert_eq!(part2(&lines), Ok(281));
+    }
+}
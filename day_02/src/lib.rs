@@ -0,0 +1,155 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::{self, Overflow, Total},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, u32},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{delimited, pair, separated_pair},
+    IResult,
+};
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq)]
+pub enum Color {
+    Red,
+    Blue,
+    Green,
+}
+
+pub type Subset = Vec<(u32, Color)>;
+
+pub struct Game {
+    pub id: u32,
+    pub subsets: Vec<Subset>,
+}
+
+fn parse_color(s: &str) -> IResult<&str, Color> {
+    alt((
+        value(Color::Blue, tag("blue")),
+        value(Color::Green, tag("green")),
+        value(Color::Red, tag("red")),
+    ))(s)
+}
+
+fn parse_cube(s: &str) -> IResult<&str, (u32, Color)> {
+    let (s, _) = multispace0(s)?;
+    separated_pair(u32, tag(" "), parse_color)(s)
+}
+
+fn parse_subset(s: &str) -> IResult<&str, Subset> {
+    separated_list1(tag(","), parse_cube)(s)
+}
+
+fn parse_game(s: &str) -> IResult<&str, Game> {
+    let game_id_parser = delimited(tag("Game "), u32, tag(":"));
+    let subsets_parser = separated_list1(tag(";"), parse_subset);
+    let parser = pair(game_id_parser, subsets_parser);
+
+    let mut x = map(parser, |(id, subsets)| Game { id, subsets });
+    x(s)
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        error::complete(s, parse_game(s))
+    }
+}
+
+impl Game {
+    pub fn is_possible(&self, color: Color, max_number: u32) -> bool {
+        self.subsets.iter().all(|subset| {
+            subset
+                .iter()
+                .filter(|(_, cube_color)| cube_color == &color)
+                .all(|(cube_count, _)| cube_count <= &max_number)
+        })
+    }
+
+    pub fn min_required_by_color(&self, color: Color) -> u32 {
+        self.subsets
+            .iter()
+            .map(|subset| {
+                subset
+                    .iter()
+                    .filter(|(_, cube_color)| cube_color == &color)
+                    .map(|(count, _)| *count)
+                    .max()
+                    .unwrap_or(0)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    error::lines(input)
+}
+
+pub fn part1(games: &[Game]) -> Result<Total, Overflow> {
+    total::sum(
+        games
+            .iter()
+            .filter(|game| game.is_possible(Color::Blue, 14))
+            .filter(|game| game.is_possible(Color::Green, 13))
+            .filter(|game| game.is_possible(Color::Red, 12))
+            .map(|game| Total::from(game.id)),
+    )
+}
+
+pub fn part2(games: &[Game]) -> Result<Total, Overflow> {
+    games.iter().try_fold(0, |sum, game| {
+        let power = total::product(
+            [Color::Blue, Color::Green, Color::Red]
+                .map(|color| Total::from(game.min_required_by_color(color))),
+        )?;
+        total::add(sum, power)
+    })
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(part2(parsed).map(Answer::from))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(2, "default", Day02);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let games = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&games), Ok(8));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let games = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&games), Ok(2286));
+    }
+}
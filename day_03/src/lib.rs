@@ -0,0 +1,175 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use itertools::Itertools;
+use std::{collections::HashMap, ops::Range};
+
+use regex::Regex;
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq)]
+pub struct Position(usize, usize);
+
+impl Position {
+    fn new(index: usize, line_length: usize) -> Position {
+        Position(index % line_length, index / line_length)
+    }
+    fn neighbors(&self) -> Vec<Position> {
+        let mut v = vec![
+            Position(self.0 + 1, self.1),
+            Position(self.0 + 1, self.1 + 1),
+            Position(self.0, self.1 + 1),
+        ];
+        if self.0 > 0 {
+            v.push(Position(self.0 - 1, self.1));
+            v.push(Position(self.0 - 1, self.1 + 1));
+            if self.1 > 0 {
+                v.push(Position(self.0 - 1, self.1 - 1));
+            }
+        }
+        if self.1 > 0 {
+            v.push(Position(self.0, self.1 - 1));
+            v.push(Position(self.0 + 1, self.1 - 1));
+        }
+        v
+    }
+    fn index(&self, line_length: usize) -> usize {
+        self.1 * line_length + self.0
+    }
+}
+
+fn neighbors(range: &Range<usize>, line_length: usize) -> Vec<Position> {
+    range
+        .to_owned()
+        .flat_map(|index| Position::new(index, line_length).neighbors())
+        .unique()
+        .collect()
+}
+
+pub struct Schematic {
+    line_length: usize,
+    symbols: HashMap<Position, char>,
+    numbers: Vec<(Range<usize>, u32)>,
+}
+
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let line_length = input
+        .find('\n')
+        .ok_or_else(|| ParseError::at_end(input, "a line break"))?
+        + 1;
+    if let Some(line) = input.lines().find(|line| line.len() != line_length - 1) {
+        let expected = format!("a line of {} characters", line_length - 1);
+        return Err(ParseError::at(input, line, expected));
+    }
+
+    let characters = input
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !matches!(c, '.' | '0'..='9' | '\n'))
+        .map(|(index, c)| (Position::new(index, line_length), c))
+        .collect::<HashMap<_, _>>();
+
+    let rx = Regex::new(r"(\d+)").unwrap();
+    let numbers = rx
+        .captures_iter(input)
+        .filter_map(|caputres| caputres.get(1))
+        .map(|m| Ok((m.range(), error::number(input, m.as_str())?)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Schematic {
+        line_length,
+        symbols: characters,
+        numbers,
+    })
+}
+
+fn find_number(index: usize, numbers: &[(Range<usize>, u32)]) -> Option<u32> {
+    numbers.iter().find_map(|(range, number)| {
+        if range.contains(&index) {
+            Some(*number)
+        } else {
+            None
+        }
+    })
+}
+
+fn product(pos: Position, numbers: &[(Range<usize>, u32)], line_length: usize) -> Option<u32> {
+    let part_numbers = pos
+        .neighbors()
+        .iter()
+        .filter_map(|pos| {
+            let pos_index = pos.index(line_length);
+            find_number(pos_index, numbers)
+        })
+        .unique()
+        .collect::<Vec<u32>>();
+    if part_numbers.len() < 2 {
+        None
+    } else {
+        Some(part_numbers.iter().product())
+    }
+}
+
+pub fn part1(schematic: &Schematic) -> u32 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|(range, _)| {
+            neighbors(range, schematic.line_length)
+                .iter()
+                .any(|pos| schematic.symbols.contains_key(pos))
+        })
+        .map(|(_, number)| number)
+        .sum::<u32>()
+}
+
+pub fn part2(schematic: &Schematic) -> u32 {
+    schematic
+        .symbols
+        .iter()
+        .filter_map(|(pos, c)| if *c == '*' { Some(pos) } else { None })
+        .filter_map(|pos| product(*pos, &schematic.numbers, schematic.line_length))
+        .sum::<u32>()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Schematic;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(3, "default", Day03);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let schematic = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&schematic), 4361);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let schematic = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&schematic), 467835);
+    }
+}
@@ -0,0 +1,138 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::{self, Overflow, Total},
+};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    having_numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn points(&self) -> Result<Total, Overflow> {
+        match self.number_of_wins() as u32 {
+            0 => Ok(0),
+            x => total::power(2, x - 1),
+        }
+    }
+
+    pub fn number_of_wins(&self) -> usize {
+        self.winning_numbers
+            .iter()
+            .filter(|winning_number| self.having_numbers.contains(winning_number))
+            .count()
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = |part: &str| {
+            part.split_ascii_whitespace()
+                .map(|number| error::number::<u32>(s, number))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let header = s
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::at(s, s, "\"Card\""))?;
+        let (id, rest) = header
+            .split_once(':')
+            .ok_or_else(|| ParseError::at_end(s, "':'"))?;
+        let id = error::number(s, id.trim())?;
+
+        let (winning_numbers, having_numbers) = rest
+            .split_once('|')
+            .ok_or_else(|| ParseError::at_end(s, "'|'"))?;
+        let winning_numbers = numbers(winning_numbers)?;
+        let having_numbers = numbers(having_numbers)?;
+        Ok(Card {
+            id,
+            winning_numbers,
+            having_numbers,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    error::lines(input)
+}
+
+pub fn part1(cards: &[Card]) -> Result<Total, Overflow> {
+    cards
+        .iter()
+        .try_fold(0, |sum, card| total::add(sum, card.points()?))
+}
+
+pub fn part2(cards: &[Card]) -> usize {
+    let max_card_id = cards
+        .iter()
+        .max_by(|card1, card2| card1.id.cmp(&card2.id))
+        .map(|card| card.id)
+        .unwrap();
+
+    let mut card_total = cards
+        .iter()
+        .map(|card| (card.id, 1))
+        .collect::<HashMap<_, _>>();
+
+    for card_id in 1..=max_card_id {
+        let card = cards.iter().find(|card| card.id == card_id).unwrap();
+        let wins = card.number_of_wins();
+        let card_amount = *card_total.get(&card_id).unwrap();
+
+        for win in card_id + 1..=card_id + (wins as u32) {
+            if let Some(x) = card_total.get_mut(&win) {
+                *x += card_amount;
+            }
+        }
+    }
+
+    card_total.values().sum::<usize>()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed<'a> = Vec<Card>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(4, "default", Day04);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let cards = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&cards), Ok(13));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let cards = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&cards), 30);
+    }
+}
@@ -0,0 +1,85 @@
+//! Random almanacs for stress testing.
+
+use common::random::Random;
+
+use crate::CATEGORIES;
+
+/// An almanac with `size` seed ranges and `size` entries in each of the seven maps. Like in
+/// the real input, every map shuffles a contiguous block of numbers: the sources cover it
+/// without overlaps, and so do the destinations.
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let block = size as u64 * 1_000_000;
+
+    let seeds = (0..size)
+        .map(|_| {
+            let start = random.below(block);
+            let length = 1 + random.below((block - start).min(block / size as u64));
+            format!("{start} {length}")
+        })
+        .collect::<Vec<_>>();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for categories in CATEGORIES.windows(2) {
+        almanac.push_str(&format!("\n{}-to-{} map:\n", categories[0], categories[1]));
+        for (destination, source, length) in map_entries(random, block, size) {
+            almanac.push_str(&format!("{destination} {source} {length}\n"));
+        }
+    }
+    almanac
+}
+
+/// Splits `0..block` into `count` pieces and moves every piece to a new place.
+fn map_entries(random: &mut Random, block: u64, count: usize) -> Vec<(u64, u64, u64)> {
+    let mut cuts = (1..count)
+        .map(|_| 1 + random.below(block - 1))
+        .collect::<Vec<_>>();
+    cuts.extend([0, block]);
+    cuts.sort_unstable();
+    cuts.dedup();
+    let pieces = cuts
+        .windows(2)
+        .map(|piece| (piece[0], piece[1] - piece[0]))
+        .collect::<Vec<_>>();
+
+    let mut order = pieces.clone();
+    random.shuffle(&mut order);
+    let mut destination = 0;
+    let mut entries = order
+        .into_iter()
+        .map(|(source, length)| {
+            let entry = (destination, source, length);
+            destination += length;
+            entry
+        })
+        .collect::<Vec<_>>();
+    random.shuffle(&mut entries);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_almanacs() {
+        for seed in 0..20 {
+            let input = generate(&mut Random::new(seed), 1 + seed as usize);
+            assert_eq!(input, generate(&mut Random::new(seed), 1 + seed as usize));
+
+            let almanac = parse(&input).unwrap();
+            assert_eq!(almanac.seeds.len(), 2 * (1 + seed as usize));
+            // The starts of the seed ranges are seeds in both parts.
+            let lowest_start = almanac
+                .seeds
+                .iter()
+                .step_by(2)
+                .map(|&seed| almanac.maps.iter().fold(seed, |value, map| map.map(value)))
+                .min()
+                .unwrap();
+            assert!(part1(&almanac) <= lowest_start);
+            assert!(part2(&almanac) <= lowest_start);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use interval::{Interval, IntervalSet};
+
+pub mod generator;
+
+pub struct Entry {
+    pub source: Interval<u64>,
+    pub destination: Interval<u64>,
+}
+pub struct Map {
+    pub entries: Vec<Entry>,
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+impl Map {
+    pub fn map(&self, input: u64) -> u64 {
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|entry| entry.source.contains(input))
+        {
+            let offset = input - entry.source.start();
+            entry.destination.start() + offset
+        } else {
+            input
+        }
+    }
+
+    /// Maps all values of `values`. Values outside of all entries stay the same.
+    pub fn map_set(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let sources = self.entries.iter().map(|entry| entry.source).collect();
+        let unmapped = values.difference(&sources);
+
+        self.entries
+            .iter()
+            .map(|entry| {
+                values
+                    .intersection(&entry.source.into())
+                    .shifted(entry.source.start(), entry.destination.start())
+                    .expect("Destinations are valid ranges.")
+            })
+            .fold(unmapped, |mapped, values| mapped.union(&values))
+    }
+}
+
+fn get_seeds(s: &str) -> Result<Vec<u64>, ParseError> {
+    let first_line = s.lines().next().unwrap_or_default();
+    first_line
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::at(s, first_line, "\"seeds: \""))?
+        .split(' ')
+        .map(|seed| error::number(s, seed))
+        .collect()
+}
+
+fn get_seeds_ranges(seeds: &[u64]) -> IntervalSet<u64> {
+    seeds
+        .chunks_exact(2)
+        .map(|range| Interval::new(range[0], range[0] + range[1]))
+        .collect()
+}
+
+fn get_map_entry(s: &str, line: &str) -> Result<Entry, ParseError> {
+    let mut parts = line.split(' ');
+    let mut next_number = || match parts.next() {
+        Some(part) => error::number::<u64>(s, part),
+        None => Err(ParseError::at(s, &line[line.len()..], "a number")),
+    };
+    let to = next_number()?;
+    let from = next_number()?;
+    let length = next_number()?;
+    Ok(Entry {
+        source: Interval::new(from, from + length),
+        destination: Interval::new(to, to + length),
+    })
+}
+
+fn get_map(s: &str, from: &str, to: &str) -> Result<Map, ParseError> {
+    let title = format!("{from}-to-{to} map:\n");
+    let location = s
+        .find(&title)
+        .ok_or_else(|| ParseError::at_end(s, format!("\"{}\"", title.trim_end())))?;
+    let entries = s[location + title.len()..]
+        .lines()
+        .take_while(|s| !s.is_empty())
+        .map(|line| get_map_entry(s, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Map { entries })
+}
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+fn get_maps(s: &str) -> Result<Vec<Map>, ParseError> {
+    CATEGORIES
+        .windows(2)
+        .map(|categories| get_map(s, categories[0], categories[1]))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    Ok(Almanac {
+        seeds: get_seeds(input)?,
+        maps: get_maps(input)?,
+    })
+}
+
+pub fn part1(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.maps.iter().fold(seed, |value, map| map.map(value)))
+        .min()
+        .unwrap()
+}
+
+pub fn part2(almanac: &Almanac) -> u64 {
+    let seeds_ranges = get_seeds_ranges(&almanac.seeds);
+
+    almanac
+        .maps
+        .iter()
+        .fold(seeds_ranges, |ranges, map| map.map_set(&ranges))
+        .min()
+        .unwrap()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = Almanac;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(5, "default", Day05);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let almanac = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&almanac), 35);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let almanac = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&almanac), 46);
+    }
+}
@@ -0,0 +1,149 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    /// Whether holding the button for `hold` milliseconds beats the record.
+    fn wins(&self, hold: u64) -> bool {
+        hold <= self.time && hold as u128 * (self.time - hold) as u128 > self.distance as u128
+    }
+}
+
+/// The hold times which beat the record: between the roots of `hold * (time - hold) = distance`.
+/// The roots are only estimated in floating point, and then corrected to the exact bounds.
+fn get_time_range(race: &Race) -> Option<RangeInclusive<u64>> {
+    let time = race.time as f64;
+    let distance = race.distance as f64;
+
+    let discriminant = time * time - 4f64 * distance;
+    if discriminant < 0.0 {
+        return None;
+    }
+    // The best hold time, and the first one from there on which no longer wins.
+    let middle = race.time / 2;
+    let mut upper = ((time + discriminant.sqrt()) / 2.0).floor() as u64;
+    while upper > middle && !race.wins(upper) {
+        upper -= 1;
+    }
+    while race.wins(upper + 1) {
+        upper += 1;
+    }
+    if !race.wins(upper) {
+        return None;
+    }
+    // The hold times which win are symmetric around half the race time.
+    Some(race.time - upper..=upper)
+}
+
+impl Race {
+    pub fn ways_to_win(&self) -> u64 {
+        get_time_range(self).map_or(0, |range| range.end() - range.start() + 1)
+    }
+}
+
+fn numbers(input: &str, line: Option<&str>, title: &str) -> Result<Vec<u64>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::at_end(input, format!("\"{title}\"")))?;
+    line.strip_prefix(title)
+        .ok_or_else(|| ParseError::at(input, line, format!("\"{title}\"")))?
+        .split(' ')
+        .filter(|s| !s.is_empty())
+        .map(|number| error::number(input, number))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = input.lines();
+    let times = numbers(input, lines.next(), "Time:")?;
+    let distances = numbers(input, lines.next(), "Distance:")?;
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+pub fn part1(races: &[Race]) -> u64 {
+    races.iter().map(|race| race.ways_to_win()).product::<u64>()
+}
+
+pub fn part2(races: &[Race]) -> u64 {
+    let time: String = races.iter().map(|race| race.time.to_string()).collect();
+    let distance: String = races.iter().map(|race| race.distance.to_string()).collect();
+
+    let race = Race {
+        time: time.parse::<u64>().unwrap(),
+        distance: distance.parse::<u64>().unwrap(),
+    };
+    race.ways_to_win()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = Vec<Race>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(6, "default", Day06);
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let races = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&races), 288);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let races = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&races), 71503);
+    }
+
+    proptest! {
+        #[test]
+        fn ways_to_win_by_brute_force(time in 0u64..300, distance in 0u64..25_000) {
+            let race = Race { time, distance };
+            let ways = (0..=time).filter(|hold| hold * (time - hold) > distance).count();
+            prop_assert_eq!(race.ways_to_win(), ways as u64);
+        }
+
+        #[test]
+        fn ways_to_win_near_the_limits(time in 1u64..100_000_000, factor in 0.9f64..1.0) {
+            // Records close to the best distance, where rounding matters most.
+            let best = (time / 2) * (time - time / 2);
+            let race = Race { time, distance: (best as f64 * factor) as u64 };
+            let range = get_time_range(&race).unwrap();
+            prop_assert!(race.wins(*range.start()) && race.wins(*range.end()));
+            prop_assert!(!race.wins(range.start() - 1) && !race.wins(range.end() + 1));
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::{self, Overflow, Total},
+};
+use itertools::{self, Itertools};
+use std::{cmp, str::FromStr};
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy)]
+pub enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+
+    Joker,
+}
+
+#[derive(Debug, Ord, PartialEq, Eq, Clone, Copy, PartialOrd)]
+pub enum Strength {
+    Five,
+    Four,
+    FullHouse,
+    Three,
+    TwoPair,
+    Pair,
+    HighCard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hand([Card; 5]);
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .char_indices()
+            .map(|(index, c)| {
+                let card = match c {
+                    'A' => Card::Ace,
+                    'K' => Card::King,
+                    'Q' => Card::Queen,
+                    'J' => Card::Jack,
+                    'T' => Card::Ten,
+                    '9' => Card::Nine,
+                    '8' => Card::Eight,
+                    '7' => Card::Seven,
+                    '6' => Card::Six,
+                    '5' => Card::Five,
+                    '4' => Card::Four,
+                    '3' => Card::Three,
+                    '2' => Card::Two,
+                    _ => return Err(ParseError::at(s, &s[index..], "a card")),
+                };
+                Ok(card)
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::at(s, s, "five cards"))?;
+
+        Ok(Self(cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match self.strength().cmp(&other.strength()) {
+            cmp::Ordering::Equal => self.0.cmp(&other.0),
+            ord => ord,
+        }
+    }
+}
+
+impl Hand {
+    pub fn with_jokers(self) -> Self {
+        Self(self.0.map(|card| match card {
+            Card::Jack => Card::Joker,
+            card => card,
+        }))
+    }
+
+    pub fn strength(&self) -> Strength {
+        let cards: Vec<_> = self
+            .0
+            .iter()
+            .sorted()
+            .dedup_with_count()
+            .sorted_by(
+                |(count1, card1), (count2, card2)| match count2.cmp(count1) {
+                    std::cmp::Ordering::Equal => card1.cmp(card2),
+                    c => c,
+                },
+            )
+            .collect_vec();
+
+        let cards = enhance_with_jokers(cards);
+
+        match cards[0] {
+            (5, _) => Strength::Five,
+            (4, _) => Strength::Four,
+            (3, _) if cards[1].0 == 2 => Strength::FullHouse,
+            (3, _) => Strength::Three,
+            (2, _) if cards[1].0 == 2 => Strength::TwoPair,
+            (2, _) => Strength::Pair,
+            (1, _) => Strength::HighCard,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn enhance_with_jokers(cards: Vec<(usize, &Card)>) -> Vec<(usize, &Card)> {
+    let (jokers, mut cards): (Vec<_>, Vec<_>) =
+        cards.iter().partition(|card| card.1 == &Card::Joker);
+    if jokers.is_empty() {
+        return cards;
+    }
+    if cards.is_empty() {
+        return jokers;
+    }
+    cards[0].0 += jokers[0].0;
+    cards
+}
+
+fn total_winnings(hands_bids: &[(Hand, u32)], jokers: bool) -> Result<Total, Overflow> {
+    hands_bids
+        .iter()
+        .map(|(hand, bid)| (if jokers { hand.with_jokers() } else { *hand }, bid))
+        .sorted_by_key(|(hand, _)| *hand)
+        .rev()
+        .enumerate()
+        .try_fold(0, |sum, (rank, (_, bid))| {
+            let winning = total::multiply(rank as Total + 1, Total::from(*bid))?;
+            total::add(sum, winning)
+        })
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Hand, u32)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], "' '"))?;
+            let hand = hand
+                .parse::<Hand>()
+                .map_err(|error| error.within(input, hand))?;
+            Ok((hand, error::number(input, bid)?))
+        })
+        .collect()
+}
+
+pub fn part1(hands_bids: &[(Hand, u32)]) -> Result<Total, Overflow> {
+    total_winnings(hands_bids, false)
+}
+
+pub fn part2(hands_bids: &[(Hand, u32)]) -> Result<Total, Overflow> {
+    total_winnings(hands_bids, true)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Vec<(Hand, u32)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(part2(parsed).map(Answer::from))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(7, "default", Day07);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let hands_bids = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&hands_bids), Ok(6440));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let hands_bids = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&hands_bids), Ok(5905));
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+
+use common::{
+    answer::Answer,
+    assumption::Assumption,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use graph::{Graph, Interner, NodeId};
+
+use num::Integer;
+
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// The nodes are connected to their left and their right successor, in that order.
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub names: Interner,
+    pub nodes: Graph,
+}
+
+fn get_map(input: &str) -> Result<(Interner, Graph), ParseError> {
+    let lines = input
+        .lines()
+        .skip(2)
+        .map(|line| {
+            let line_end = &line[line.len()..];
+            let (first, targets) = line
+                .split_once(" = (")
+                .ok_or_else(|| ParseError::at(input, line_end, "\" = (\""))?;
+            let (left, right) = targets
+                .strip_suffix(')')
+                .ok_or_else(|| ParseError::at(input, line_end, "')'"))?
+                .split_once(", ")
+                .ok_or_else(|| ParseError::at(input, targets, "\", \""))?;
+            Ok((first, left, right))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let defined = lines
+        .iter()
+        .map(|(first, _, _)| *first)
+        .collect::<HashSet<_>>();
+
+    let mut names = Interner::new();
+    let mut nodes = Graph::new();
+    for (first, left, right) in lines {
+        for target in [left, right] {
+            if !defined.contains(target) {
+                return Err(ParseError::at(
+                    input,
+                    target,
+                    "a node defined in the network",
+                ));
+            }
+        }
+        let first = names.intern(first);
+        nodes.add_edge(first, names.intern(left), ());
+        nodes.add_edge(first, names.intern(right), ());
+    }
+    Ok((names, nodes))
+}
+
+fn successor(network: &Network, node: NodeId, direction: &Direction) -> NodeId {
+    let successors = network.nodes.edges(node);
+    match direction {
+        Direction::Left => successors[0].to,
+        Direction::Right => successors[1].to,
+    }
+}
+
+fn is_end(network: &Network, node: NodeId) -> bool {
+    network.names.resolve(node).ends_with('Z')
+}
+
+/// The first node ending with 'Z' reached from `start`, following the directions from the
+/// one at `offset` on, and the steps to it. `None` if the walk runs into a loop without one.
+fn walk_to_end(network: &Network, start: NodeId, offset: usize) -> Option<(NodeId, usize)> {
+    let directions = network.directions.len();
+    let states = network.nodes.node_count() * directions;
+    let mut current = start;
+    for steps in 1..=states {
+        current = successor(
+            network,
+            current,
+            &network.directions[(offset + steps - 1) % directions],
+        );
+        if is_end(network, current) {
+            return Some((current, steps));
+        }
+    }
+    None
+}
+
+fn get_steps(start: NodeId, network: &Network) -> usize {
+    network
+        .directions
+        .iter()
+        .cycle()
+        .scan(start, |current, direction| {
+            *current = successor(network, *current, direction);
+            Some(*current)
+        })
+        // It's not the correct end condition for part 1, but it works for part 1 and 2
+        .take_while(|current| !is_end(network, *current))
+        .count()
+        + 1
+}
+
+fn get_directions(input: &str) -> Result<Vec<Direction>, ParseError> {
+    input
+        .char_indices()
+        .take_while(|(_, c)| !c.is_ascii_whitespace())
+        .map(|(index, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::at(input, &input[index..], "'L' or 'R'")),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|directions| match directions.is_empty() {
+            true => Err(ParseError::at(input, input, "'L' or 'R'")),
+            false => Ok(directions),
+        })
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let directions = get_directions(input)?;
+    let (names, nodes) = get_map(input)?;
+    Ok(Network {
+        directions,
+        names,
+        nodes,
+    })
+}
+
+pub fn part1(network: &Network) -> usize {
+    let start = network.names.get("AAA").expect("There is no node AAA.");
+    get_steps(start, network)
+}
+
+pub fn part2(network: &Network) -> usize {
+    let starts = network
+        .names
+        .iter()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(id, _)| id);
+    let counts = starts
+        .map(|start| get_steps(start, network))
+        .collect::<Vec<_>>();
+
+    counts
+        .iter()
+        .copied()
+        .reduce(|prev, count| prev.lcm(&count))
+        .unwrap()
+}
+
+/// Whether the walk, having reached `end` after `steps` steps, keeps reaching nodes ending with
+/// 'Z' every `steps` steps, and is back at `end` with the same directions ahead after a period.
+fn repeats(network: &Network, end: NodeId, steps: usize) -> bool {
+    let period = steps.lcm(&network.directions.len());
+    let mut current = end;
+    for hop in 1..=period / steps {
+        match walk_to_end(network, current, hop * steps) {
+            Some((next, more)) if more == steps => current = next,
+            _ => return false,
+        }
+    }
+    current == end
+}
+
+/// The properties of the network which make the first node ending with 'Z' the goal, and
+/// for part 2 the least common multiple of the steps to it the answer.
+pub fn assumptions(network: &Network, part: u8) -> Vec<Assumption> {
+    if part == 1 {
+        let start = network.names.get("AAA");
+        let goal = network.names.get("ZZZ");
+        let reached = start.and_then(|start| walk_to_end(network, start, 0));
+        return vec![
+            Assumption::new(
+                "There are nodes AAA and ZZZ.",
+                start.is_some() && goal.is_some(),
+            ),
+            Assumption::new(
+                "The first node ending with 'Z' reached from AAA is ZZZ.",
+                reached.is_some_and(|(end, _)| Some(end) == goal),
+            ),
+        ];
+    }
+
+    let ends = network
+        .names
+        .iter()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(start, _)| walk_to_end(network, start, 0))
+        .collect::<Vec<_>>();
+    let cycles = ends
+        .iter()
+        .all(|end| end.is_some_and(|(end, steps)| repeats(network, end, steps)));
+    vec![
+        Assumption::new("There are nodes ending with 'A'.", !ends.is_empty()),
+        Assumption::new(
+            "Every start reaches a node ending with 'Z'.",
+            ends.iter().all(Option::is_some),
+        ),
+        Assumption::new(
+            "Every start reaches nodes ending with 'Z' exactly at the multiples of the steps to \
+             the first one.",
+            cycles,
+        ),
+    ]
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = Network;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+
+    fn assumptions(&self, parsed: &Self::Parsed<'_>, part: u8) -> Vec<Assumption> {
+        assumptions(parsed, part)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(8, "default", Day08);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo1_part1() {
+        let network = parse(include_str!("../data/demo_input1.txt")).unwrap();
+        assert_eq!(part1(&network), 2);
+    }
+
+    #[test]
+    fn demo2_part1() {
+        let network = parse(include_str!("../data/demo_input2.txt")).unwrap();
+        assert_eq!(part1(&network), 6);
+    }
+
+    #[test]
+    fn demo_assumptions() {
+        for demo in [
+            include_str!("../data/demo_input1.txt"),
+            include_str!("../data/demo_input2.txt"),
+        ] {
+            let network = parse(demo).unwrap();
+            assert!(assumptions(&network, 1)
+                .iter()
+                .all(|assumption| assumption.holds));
+        }
+        let network = parse(include_str!("../data/demo_input3.txt")).unwrap();
+        assert!(assumptions(&network, 2)
+            .iter()
+            .all(|assumption| assumption.holds));
+
+        // The start reaches the end after three steps, and then again after two.
+        let network = parse(concat!(
+            "L\n\nAAA = (BBB, BBB)\nBBB = (CCC, CCC)\nCCC = (ZZZ, ZZZ)\n",
+            "ZZZ = (DDD, DDD)\nDDD = (ZZZ, ZZZ)\n"
+        ))
+        .unwrap();
+        let failed = assumptions(&network, 2)
+            .into_iter()
+            .filter(|assumption| !assumption.holds)
+            .count();
+        assert_eq!(failed, 1);
+        assert!(assumptions(&network, 1)
+            .iter()
+            .all(|assumption| assumption.holds));
+    }
+
+    #[test]
+    fn demo3_part2() {
+        let network = parse(include_str!("../data/demo_input3.txt")).unwrap();
+        assert_eq!(part2(&network), 6);
+    }
+
+    #[test]
+    fn invalid_networks() {
+        let error = parse("\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (1, 1, "'L' or 'R'")
+        );
+
+        let error = parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.text, "BBB");
+    }
+}
@@ -0,0 +1,93 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::{self, Overflow, SignedTotal},
+};
+
+fn differences(data: &[SignedTotal]) -> Result<Vec<SignedTotal>, Overflow> {
+    data.windows(2)
+        .map(|pair| total::subtract(pair[1], pair[0]))
+        .collect()
+}
+
+pub fn extrapolate_next(data: &[SignedTotal]) -> Result<SignedTotal, Overflow> {
+    let diffs = differences(data)?;
+    if diffs.iter().all(|diff| diff == &0) {
+        Ok(data[0])
+    } else {
+        total::add(*data.last().unwrap(), extrapolate_next(&diffs)?)
+    }
+}
+
+pub fn extrapolate_previous(data: &[SignedTotal]) -> Result<SignedTotal, Overflow> {
+    let diffs = differences(data)?;
+    if diffs.iter().all(|diff| diff == &0) {
+        Ok(data[0])
+    } else {
+        total::subtract(*data.first().unwrap(), extrapolate_previous(&diffs)?)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<SignedTotal>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|number| error::number(input, number))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(data_histories: &[Vec<SignedTotal>]) -> Result<SignedTotal, Overflow> {
+    data_histories
+        .iter()
+        .try_fold(0, |sum, data| total::add(sum, extrapolate_next(data)?))
+}
+
+pub fn part2(data_histories: &[Vec<SignedTotal>]) -> Result<SignedTotal, Overflow> {
+    data_histories
+        .iter()
+        .try_fold(0, |sum, data| total::add(sum, extrapolate_previous(data)?))
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Vec<SignedTotal>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(part2(parsed).map(Answer::from))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(9, "default", Day09);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let data_histories = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&data_histories), Ok(114));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let data_histories = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&data_histories), Ok(2));
+    }
+}
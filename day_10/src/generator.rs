@@ -0,0 +1,172 @@
+//! Random pipe maps for stress testing.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use common::random::Random;
+
+type Point = (usize, usize);
+
+/// A square map with sides of `size` tiles, rounded down to a multiple of four, and a closed loop
+/// through the start, surrounded by random pipes. The loop runs around a random spanning tree of
+/// a lattice of `size / 4` by `size / 4` nodes, drawn as corridors two tiles wide, so it never
+/// touches itself.
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let nodes = (size / 4).max(2);
+    let corridors = spanning_tree(random, nodes)
+        .into_iter()
+        .flat_map(|(x, y)| {
+            [
+                (2 * x, 2 * y),
+                (2 * x + 1, 2 * y),
+                (2 * x, 2 * y + 1),
+                (2 * x + 1, 2 * y + 1),
+            ]
+        })
+        .collect();
+    let outline = outline(&corridors);
+
+    let side = 4 * nodes;
+    let mut tiles = vec![vec!['.'; side]; side];
+    for row in &mut tiles {
+        for tile in row {
+            *tile = *random.choose(&['|', '-', 'L', 'J', '7', 'F', '.', '.']);
+        }
+    }
+    for (index, &(x, y)) in outline.iter().enumerate() {
+        let previous = outline[(index + outline.len() - 1) % outline.len()];
+        let next = outline[(index + 1) % outline.len()];
+        tiles[y][x] = pipe((x, y), previous, next);
+    }
+
+    let (x, y) = *random.choose(&outline);
+    tiles[y][x] = 'S';
+    // Pipes off the loop must not look as if they connected to the start.
+    let loop_tiles = outline.iter().collect::<HashSet<_>>();
+    let neighbours = [
+        (x.wrapping_sub(1), y, ['-', 'L', 'F']),
+        (x + 1, y, ['-', 'J', '7']),
+        (x, y.wrapping_sub(1), ['|', '7', 'F']),
+        (x, y + 1, ['|', 'L', 'J']),
+    ];
+    for (x, y, connecting) in neighbours {
+        if x < side
+            && y < side
+            && !loop_tiles.contains(&(x, y))
+            && connecting.contains(&tiles[y][x])
+        {
+            tiles[y][x] = '.';
+        }
+    }
+
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// The cells covered by a random spanning tree of the `size` by `size` lattice: the nodes at
+/// even coordinates and the edges between them.
+fn spanning_tree(random: &mut Random, size: usize) -> HashSet<Point> {
+    let start = (random.index(size), random.index(size));
+    let mut visited = HashSet::from([start]);
+    let mut cells = HashSet::from([(2 * start.0, 2 * start.1)]);
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(|&(x, y)| x < size && y < size && !visited.contains(&(x, y)))
+        .collect::<Vec<_>>();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = *random.choose(&unvisited);
+        visited.insert(next);
+        cells.insert((2 * next.0, 2 * next.1));
+        cells.insert((x + next.0, y + next.1));
+        stack.push(next);
+    }
+    cells
+}
+
+/// The corners of the cells on the border of `cells`, in clockwise order. The cells must form
+/// a region without holes whose cells never touch only diagonally.
+fn outline(cells: &HashSet<Point>) -> Vec<Point> {
+    let mut next = HashMap::new();
+    for &(x, y) in cells {
+        let outside = |dx: isize, dy: isize| {
+            let neighbour = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+            !cells.contains(&neighbour)
+        };
+        if outside(0, -1) {
+            next.insert((x, y), (x + 1, y));
+        }
+        if outside(1, 0) {
+            next.insert((x + 1, y), (x + 1, y + 1));
+        }
+        if outside(0, 1) {
+            next.insert((x + 1, y + 1), (x, y + 1));
+        }
+        if outside(-1, 0) {
+            next.insert((x, y + 1), (x, y));
+        }
+    }
+
+    let start = *next.keys().min().expect("The region is not empty.");
+    let mut outline = vec![start];
+    let mut corner = next[&start];
+    while corner != start {
+        outline.push(corner);
+        corner = next[&corner];
+    }
+    assert_eq!(outline.len(), next.len(), "The region has a hole.");
+    outline
+}
+
+/// The pipe at `point` connecting its two neighbours on the loop.
+fn pipe(point: Point, previous: Point, next: Point) -> char {
+    let side = |(x, y): Point| match (x.cmp(&point.0), y.cmp(&point.1)) {
+        (Ordering::Less, _) => 'W',
+        (Ordering::Greater, _) => 'E',
+        (_, Ordering::Less) => 'N',
+        _ => 'S',
+    };
+    match (side(previous), side(next)) {
+        ('N', 'S') | ('S', 'N') => '|',
+        ('W', 'E') | ('E', 'W') => '-',
+        ('N', 'E') | ('E', 'N') => 'L',
+        ('N', 'W') | ('W', 'N') => 'J',
+        ('S', 'W') | ('W', 'S') => '7',
+        _ => 'F',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_loops() {
+        for seed in 0..20 {
+            let nodes = 2 + seed as usize;
+            let input = generate(&mut Random::new(seed), 4 * nodes);
+            assert_eq!(input, generate(&mut Random::new(seed), 4 * nodes));
+            assert_eq!(input.lines().count(), 4 * nodes);
+
+            let map = parse(&input).unwrap();
+            // The tree covers 4 * (2 * nodes² - 1) cells, and its outline has 8 * nodes² tiles.
+            // By Pick's theorem, the outline encloses 4 * nodes² - 3 tiles.
+            assert_eq!(part1(&map), 4 * nodes * nodes);
+            assert_eq!(part2(&map), (4 * nodes * nodes - 3) as u64);
+        }
+    }
+}
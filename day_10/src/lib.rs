@@ -0,0 +1,323 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    polygon::Polygon,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use grid::{
+    render::{Canvas, Colour},
+    Direction, Grid, Position,
+};
+use std::{iter::once, str::FromStr};
+
+pub mod generator;
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum MapTile {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+pub struct Map {
+    fields: Grid<MapTile>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = Grid::parse(
+            s,
+            |c| match c {
+                '|' => Some(MapTile::Vertical),
+                '-' => Some(MapTile::Horizontal),
+                'L' => Some(MapTile::NorthEast),
+                'J' => Some(MapTile::NorthWest),
+                '7' => Some(MapTile::SouthWest),
+                'F' => Some(MapTile::SouthEast),
+                '.' => Some(MapTile::Ground),
+                'S' => Some(MapTile::Start),
+                _ => None,
+            },
+            "a pipe, '.' or 'S'",
+        )?;
+        let map = Map { fields };
+
+        let start = s
+            .find('S')
+            .ok_or_else(|| ParseError::at_end(s, "a start 'S'"))?;
+        if map.start_directions(map.start()).len() < 2 {
+            return Err(ParseError::at(
+                s,
+                &s[start..],
+                "a start 'S' between two connecting pipes",
+            ));
+        }
+        Ok(map)
+    }
+}
+
+impl Map {
+    fn start(&self) -> Position {
+        self.fields
+            .position(|pipe| pipe == &MapTile::Start)
+            .expect("The parser checks that there is a start.")
+    }
+
+    /// The directions from the start towards pipes which connect to it.
+    fn start_directions(&self, start_coordinate: Position) -> Vec<Direction> {
+        let directions = [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ];
+
+        directions
+            .iter()
+            .filter(|direction| {
+                if let Some(field) = start_coordinate.step(**direction) {
+                    if let Some(pipe) = self.get(field) {
+                        return match direction {
+                            Direction::Left => {
+                                pipe == MapTile::Horizontal
+                                    || pipe == MapTile::NorthEast
+                                    || pipe == MapTile::SouthEast
+                            }
+                            Direction::Right => {
+                                pipe == MapTile::Horizontal
+                                    || pipe == MapTile::NorthWest
+                                    || pipe == MapTile::SouthWest
+                            }
+                            Direction::Up => {
+                                pipe == MapTile::Vertical
+                                    || pipe == MapTile::SouthWest
+                                    || pipe == MapTile::SouthEast
+                            }
+                            Direction::Down => {
+                                pipe == MapTile::Vertical
+                                    || pipe == MapTile::NorthWest
+                                    || pipe == MapTile::NorthEast
+                            }
+                        };
+                    }
+                }
+                false
+            })
+            .copied()
+            .collect()
+    }
+
+    fn get_start(&self) -> (Position, Direction) {
+        let start_coordinate = self.start();
+        let start_direction = self.start_directions(start_coordinate)[0];
+
+        (start_coordinate, start_direction)
+    }
+
+    fn get(&self, coordinate: Position) -> Option<MapTile> {
+        self.fields.get(coordinate).copied()
+    }
+
+    fn iter(&self, coordinate: Position, direction: Direction) -> MapWalker<'_> {
+        MapWalker {
+            map: self,
+            coordinate,
+            direction,
+        }
+    }
+}
+
+struct MapWalker<'a> {
+    coordinate: Position,
+    direction: Direction,
+    map: &'a Map,
+}
+
+impl Iterator for MapWalker<'_> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(candidate) = self.coordinate.step(self.direction) {
+            if let Some(pipe) = self.map.get(candidate) {
+                let direction = match pipe {
+                    MapTile::Vertical => self.direction,
+                    MapTile::Horizontal => self.direction,
+                    MapTile::NorthEast if self.direction == Direction::Down => Direction::Right,
+                    MapTile::NorthEast => Direction::Up,
+                    MapTile::NorthWest if self.direction == Direction::Down => Direction::Left,
+                    MapTile::NorthWest => Direction::Up,
+                    MapTile::SouthWest if self.direction == Direction::Up => Direction::Left,
+                    MapTile::SouthWest => Direction::Down,
+                    MapTile::SouthEast if self.direction == Direction::Up => Direction::Right,
+                    MapTile::SouthEast => Direction::Down,
+                    MapTile::Ground => return None,
+                    MapTile::Start => self.direction,
+                };
+                self.coordinate = candidate;
+                self.direction = direction;
+                return Some(self.coordinate);
+            }
+        }
+        None
+    }
+}
+
+fn get_path(map: &Map) -> Vec<Position> {
+    let (start_position, start_direction) = map.get_start();
+
+    map.iter(start_position, start_direction)
+        .take_while(|coordinate| coordinate != &start_position)
+        .chain(once(start_position))
+        .collect::<Vec<_>>()
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse::<Map>()
+}
+
+pub fn part1(map: &Map) -> usize {
+    let steps = get_path(map).len();
+
+    steps / 2
+}
+
+/// The loop as a polygon through the tiles where it turns. The start may be a straight pipe,
+/// which does no harm as a corner.
+fn loop_polygon(map: &Map, path: &[Position]) -> Polygon {
+    path.iter()
+        .filter(|position| {
+            !matches!(
+                map.get(**position),
+                Some(MapTile::Vertical | MapTile::Horizontal)
+            )
+        })
+        .map(|position| position.try_convert().unwrap())
+        .collect()
+}
+
+pub fn part2(map: &Map) -> u64 {
+    loop_polygon(map, &get_path(map)).interior_points()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Map;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(10, "default", Day10);
+}
+
+/// The tiles strictly inside the loop.
+fn enclosed_tiles(map: &Map, path: &[Position]) -> Vec<Position> {
+    let polygon = loop_polygon(map, path);
+    map.fields
+        .iter()
+        .map(|(position, _)| position)
+        .filter(|position| polygon.contains(position.try_convert().unwrap()))
+        .collect()
+}
+
+/// Draws the pipes with the loop highlighted, and for part 2 also the enclosed tiles.
+pub fn render(map: &Map, part: u8) -> Canvas {
+    let path = get_path(map);
+    let canvas = Canvas::new(&map.fields, |tile| match tile {
+        MapTile::Vertical => '│',
+        MapTile::Horizontal => '─',
+        MapTile::NorthEast => '└',
+        MapTile::NorthWest => '┘',
+        MapTile::SouthWest => '┐',
+        MapTile::SouthEast => '┌',
+        MapTile::Ground => '.',
+        MapTile::Start => 'S',
+    })
+    .overlay(path.iter().copied(), Colour::Yellow, None);
+
+    match part {
+        1 => canvas,
+        _ => canvas.overlay(enclosed_tiles(map, &path), Colour::Green, Some('I')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo1_part1() {
+        let map = parse(include_str!("../data/demo_input1.txt")).unwrap();
+        assert_eq!(part1(&map), 4);
+    }
+
+    #[test]
+    fn demo2_part1() {
+        let map = parse(include_str!("../data/demo_input2.txt")).unwrap();
+        assert_eq!(part1(&map), 8);
+    }
+
+    #[test]
+    fn demo3_part2() {
+        let map = parse(include_str!("../data/demo_input3.txt")).unwrap();
+        assert_eq!(part2(&map), 4);
+    }
+
+    #[test]
+    fn demo4_part2() {
+        let map = parse(include_str!("../data/demo_input4.txt")).unwrap();
+        assert_eq!(part2(&map), 8);
+    }
+
+    #[test]
+    fn rendered_tiles() {
+        for demo in [
+            include_str!("../data/demo_input3.txt"),
+            include_str!("../data/demo_input4.txt"),
+            include_str!("../data/demo_input5.txt"),
+        ] {
+            let map = parse(demo).unwrap();
+            let enclosed = render(&map, 2).to_string().matches('I').count();
+            assert_eq!(enclosed as u64, part2(&map));
+        }
+    }
+
+    #[test]
+    fn demo5_part2() {
+        let map = parse(include_str!("../data/demo_input5.txt")).unwrap();
+        assert_eq!(part2(&map), 10);
+    }
+
+    #[test]
+    fn invalid_starts() {
+        let error = "-L|\n-7|".parse::<Map>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = ".S-\n...".parse::<Map>().err().unwrap();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert_eq!(error.expected, "a start 'S' between two connecting pipes");
+
+        let map = "-S-\n...".parse::<Map>().unwrap();
+        assert_eq!(map.get_start().1, Direction::Left);
+    }
+}
@@ -0,0 +1,165 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    geometry::Coordinate,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use std::str::FromStr;
+
+use itertools::iproduct;
+
+pub type Position = Coordinate<usize>;
+
+#[derive(Clone)]
+pub struct Space {
+    galaxies: Vec<Position>,
+    height: usize,
+    width: usize,
+}
+
+impl FromStr for Space {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let width = input.find('\n').unwrap_or(input.len());
+        let height = (input.len() + 1) / (width + 1);
+        let galaxies = input
+            .char_indices()
+            .filter_map(|(pos, c)| match c {
+                '#' => Some(Ok(Position {
+                    x: pos % (width + 1),
+                    y: pos / (width + 1),
+                })),
+                '.' | '\n' => None,
+                _ => Some(Err(ParseError::at(input, &input[pos..], "'#' or '.'"))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Space {
+            galaxies,
+            height,
+            width,
+        })
+    }
+}
+
+impl Space {
+    fn expand(mut self, expansion: usize) -> Self {
+        let empty_rows = self.empty_rows();
+        let empty_columns = self.empty_columns();
+        let height = self.height + empty_rows.len();
+        let width = self.width + empty_columns.len();
+
+        empty_rows.iter().for_each(|row_index| {
+            self.galaxies
+                .iter_mut()
+                .filter(|galaxy| galaxy.y > *row_index)
+                .for_each(|galaxy| galaxy.y += expansion)
+        });
+        empty_columns.iter().for_each(|column_index| {
+            self.galaxies
+                .iter_mut()
+                .filter(|galaxy| galaxy.x > *column_index)
+                .for_each(|galaxy| galaxy.x += expansion)
+        });
+        Self {
+            height,
+            width,
+            ..self
+        }
+    }
+
+    fn num_galaxies(&self) -> usize {
+        self.galaxies.len()
+    }
+
+    fn empty_rows(&self) -> Vec<usize> {
+        (0..self.height)
+            .filter(|column| self.galaxies.iter().all(|galaxy| galaxy.y != *column))
+            .rev()
+            .collect()
+    }
+    fn empty_columns(&self) -> Vec<usize> {
+        (0..self.width)
+            .filter(|column| self.galaxies.iter().all(|galaxy| galaxy.x != *column))
+            .rev()
+            .collect()
+    }
+
+    fn galaxy_distance(&self, idx1: usize, idx2: usize) -> Option<u64> {
+        let galaxy1 = &self.galaxies[idx1];
+        let galaxy2 = &self.galaxies[idx2];
+        galaxy1.manhattan_distance(galaxy2)
+    }
+}
+
+/// The sum of the distances between all pairs of galaxies, unless it does not fit into an `u64`.
+pub fn sum_of_distances(space: &Space, expansion: usize) -> Option<u64> {
+    let space = space.clone().expand(expansion);
+
+    iproduct!(0..space.num_galaxies(), 0..space.num_galaxies())
+        .filter(|(galaxy_1, galaxy2)| galaxy_1 < galaxy2)
+        .try_fold(0u64, |sum, (galaxy1, galaxy2)| {
+            sum.checked_add(space.galaxy_distance(galaxy1, galaxy2)?)
+        })
+}
+
+pub fn parse(input: &str) -> Result<Space, ParseError> {
+    input.parse::<Space>()
+}
+
+pub fn part1(space: &Space) -> u64 {
+    sum_of_distances(space, 1).expect("The sum of the distances does not fit into an u64.")
+}
+
+pub fn part2(space: &Space) -> u64 {
+    sum_of_distances(space, 1000000 - 1)
+        .expect("The sum of the distances does not fit into an u64.")
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Space;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(11, "default", Day11);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let space = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&space), 374);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let space = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&space), 82000210);
+    }
+
+    #[test]
+    fn demo_expansions() {
+        let space = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(sum_of_distances(&space, 10 - 1), Some(1030));
+        assert_eq!(sum_of_distances(&space, 100 - 1), Some(8410));
+    }
+}
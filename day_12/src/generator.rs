@@ -0,0 +1,55 @@
+//! Random rows of springs for stress testing.
+
+use common::random::Random;
+
+/// `size` rows of up to 20 springs. Every row is made from a random arrangement, of which half
+/// the springs are then hidden as unknown, so that it has at least one possible arrangement.
+pub fn generate(random: &mut Random, size: usize) -> String {
+    (0..size)
+        .map(|_| row(random))
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+fn row(random: &mut Random) -> String {
+    let length = 1 + random.index(20);
+    let mut damaged = (0..length).map(|_| random.chance(1, 2)).collect::<Vec<_>>();
+    let any = random.index(length);
+    damaged[any] = true;
+
+    let groups = damaged
+        .split(|damaged| !damaged)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len().to_string())
+        .collect::<Vec<_>>();
+    let springs = damaged
+        .iter()
+        .map(|damaged| match (random.chance(1, 2), damaged) {
+            (true, _) => '?',
+            (false, true) => '#',
+            (false, false) => '.',
+        })
+        .collect::<String>();
+
+    format!("{springs} {}", groups.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    #[test]
+    fn generated_rows() {
+        for seed in 0..20 {
+            let input = generate(&mut Random::new(seed), 10);
+            assert_eq!(input, generate(&mut Random::new(seed), 10));
+
+            let rows = parse(&input).unwrap();
+            assert_eq!(rows.len(), 10);
+            assert!(rows.iter().all(|row| row.get_combination_count() >= 1));
+            assert!(part1(&rows) >= 10);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use std::{collections::HashMap, iter};
+
+use itertools::Itertools;
+use tracing::debug;
+
+pub mod generator;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum State {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct Row {
+    springs: Vec<State>,
+    groups: Vec<usize>,
+}
+
+fn get_combination_count_all_unknown(spring_count: usize, group_count: usize) -> usize {
+    if group_count == 1 {
+        return spring_count;
+    }
+
+    let min_req = group_count * 2 - 1;
+    let Some(additionals) = spring_count.checked_sub(min_req) else {
+        // Too few springs for the groups and the gaps between them.
+        return 0;
+    };
+    if additionals == 0 {
+        // The only possible solution
+        return 1;
+    }
+    (0..=additionals)
+        .map(|index| {
+            let x = (group_count - 1) + additionals - index;
+            num::integer::binomial(x, group_count - 1)
+        })
+        .sum::<usize>()
+}
+
+fn get_combination_count(springs: &[State], groups: &[usize]) -> usize {
+    count_combinations(springs, groups, &mut HashMap::new())
+}
+
+/// The recursion only ever continues with the tails of `springs` and `groups`, so their lengths
+/// identify the counts in `cache`.
+fn count_combinations(
+    springs: &[State],
+    groups: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    let key = (springs.len(), groups.len());
+    if let Some(count) = cache.get(&key) {
+        return *count;
+    }
+    let count = count_uncached(springs, groups, cache);
+    cache.insert(key, count);
+    count
+}
+
+fn count_uncached(
+    springs: &[State],
+    groups: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if groups.is_empty() {
+        if springs.iter().all(|spring| *spring != State::Damaged) {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    if springs.iter().all(|state| *state == State::Unknown) {
+        // If all groups are just "1",
+        let to_reduce = groups.iter().sum::<usize>() - groups.len();
+        let Some(remaining_springs) = springs.len().checked_sub(to_reduce) else {
+            return 0;
+        };
+        return get_combination_count_all_unknown(remaining_springs, groups.len());
+    }
+
+    let group = groups[0];
+
+    let needed_space = groups.iter().sum::<usize>() + groups.len() - 1;
+    let last_possible_index = match springs.len().checked_sub(needed_space) {
+        Some(value) => springs
+            .iter()
+            .position(|spring| *spring == State::Damaged)
+            .unwrap_or(value)
+            .min(value),
+        None => return 0,
+    };
+
+    (0..=last_possible_index)
+        .map(|index| match springs[index] {
+            State::Operational => 0,
+            _ => {
+                if springs[index..index + group]
+                    .iter()
+                    .all(|spring| *spring != State::Operational)
+                    && springs
+                        .get(index + group)
+                        .is_none_or(|end| *end != State::Damaged)
+                {
+                    let next_start = index + group + springs.get(index + group).map_or(0, |_| 1);
+                    count_combinations(&springs[next_start..], &groups[1..], cache)
+                } else {
+                    0
+                }
+            }
+        })
+        .sum::<usize>()
+}
+
+impl Row {
+    pub fn get_combination_count(&self) -> usize {
+        get_combination_count(&self.springs, &self.groups)
+    }
+
+    pub fn get_combination_count_unfolded(&self) -> usize {
+        let springs = iter::repeat_n(&self.springs, 5)
+            .interleave_shortest(iter::repeat_n(&vec![State::Unknown], 4))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        get_combination_count(&springs, &self.groups.repeat(5))
+    }
+}
+
+mod parser {
+    use nom::{
+        character::complete::{self, newline, one_of, space1, u32},
+        combinator::map,
+        multi::{many0, separated_list0},
+        sequence::separated_pair,
+        IResult, Parser,
+    };
+
+    use common::error::{self, ParseError};
+
+    use crate::Row;
+    use crate::State;
+
+    fn springs(s: &str) -> IResult<&str, Vec<State>> {
+        let states = one_of(".#?").map(|c| match c {
+            '.' => State::Operational,
+            '#' => State::Damaged,
+            '?' => State::Unknown,
+            _ => unreachable!(),
+        });
+        many0(states)(s)
+    }
+
+    fn groups(s: &str) -> IResult<&str, Vec<usize>> {
+        let usize = map(u32, |number| number as usize);
+        separated_list0(complete::char(','), usize)(s)
+    }
+
+    pub(crate) fn row(s: &str) -> IResult<&str, Row> {
+        map(
+            separated_pair(springs, space1, groups),
+            |(springs, groups)| Row { springs, groups },
+        )(s)
+    }
+
+    pub fn parse(s: &str) -> Result<Vec<Row>, ParseError> {
+        error::complete(s, separated_list0(newline, row)(s))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    parser::parse(input)
+}
+
+pub fn part1(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|row| row.get_combination_count())
+        .enumerate()
+        .inspect(|(row, count)| debug!(row, count, "Arrangements of the row"))
+        .map(|(_, count)| count)
+        .sum::<usize>()
+}
+
+pub fn part2(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|row| row.get_combination_count_unfolded())
+        .enumerate()
+        .inspect(|(row, count)| debug!(row, count, "Arrangements of the unfolded row"))
+        .map(|(_, count)| count)
+        .sum::<usize>()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<Row>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(12, "default", Day12);
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Counts the arrangements by trying every combination for the unknown springs.
+    fn brute_force_count(springs: &[State], groups: &[usize]) -> usize {
+        let unknowns = springs
+            .iter()
+            .filter(|spring| **spring == State::Unknown)
+            .count();
+        (0..1u32 << unknowns)
+            .filter(|combination| {
+                let mut unknown = 0;
+                let damaged = springs
+                    .iter()
+                    .map(|spring| match spring {
+                        State::Unknown => {
+                            unknown += 1;
+                            combination & 1 << (unknown - 1) != 0
+                        }
+                        spring => *spring == State::Damaged,
+                    })
+                    .collect::<Vec<_>>();
+                let arranged = damaged
+                    .split(|damaged| !damaged)
+                    .map(<[bool]>::len)
+                    .filter(|len| *len > 0)
+                    .collect::<Vec<_>>();
+                arranged == groups
+            })
+            .count()
+    }
+
+    fn springs(max_len: usize) -> impl Strategy<Value = Vec<State>> {
+        let state = prop_oneof![
+            Just(State::Operational),
+            Just(State::Damaged),
+            Just(State::Unknown)
+        ];
+        prop::collection::vec(state, 0..=max_len)
+    }
+
+    fn groups() -> impl Strategy<Value = Vec<usize>> {
+        prop::collection::vec(1usize..4, 0..5)
+    }
+
+    proptest! {
+        #[test]
+        fn all_unknown_by_brute_force(len in 0usize..=14, groups in groups()) {
+            let springs = vec![State::Unknown; len];
+            prop_assert_eq!(
+                get_combination_count(&springs, &groups),
+                brute_force_count(&springs, &groups)
+            );
+        }
+
+        #[test]
+        fn any_row_by_brute_force(springs in springs(14), groups in groups()) {
+            prop_assert_eq!(
+                get_combination_count(&springs, &groups),
+                brute_force_count(&springs, &groups)
+            );
+        }
+    }
+
+    #[test]
+    fn demo_part1() {
+        let rows = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&rows), 21);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let rows = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&rows), 525152);
+    }
+    #[test]
+    fn test_1() {
+        let row = parser::row("???.###    1,1,3").unwrap().1;
+
+        assert_eq!(row.get_combination_count_unfolded(), 1);
+    }
+
+    #[test]
+    fn test_2() {
+        let row = parser::row("????     2").unwrap().1;
+
+        assert_eq!(row.get_combination_count(), 3);
+        assert_eq!(row.get_combination_count_unfolded(), 3003);
+    }
+
+    #[test]
+    fn test_7_unknowns() {
+        let row = parser::row("???????     1,1,1,1").unwrap().1;
+        assert_eq!(row.get_combination_count(), 1);
+
+        let row = parser::row("???????     1,1,1").unwrap().1;
+        assert_eq!(row.get_combination_count(), 10);
+
+        let row = parser::row("???????     1,1").unwrap().1;
+        assert_eq!(row.get_combination_count(), 15);
+
+        let row = parser::row("???????     1").unwrap().1;
+        assert_eq!(row.get_combination_count(), 7);
+    }
+}
@@ -0,0 +1,165 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use grid::Grid;
+use std::iter::{self};
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternType {
+    Ash,
+    Rock,
+}
+
+#[derive(Debug)]
+pub struct Pattern {
+    fields: Grid<PatternType>,
+}
+
+#[derive(Debug)]
+pub enum MirrorLine {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+/// Number of fields that differ from their mirror image, if the mirror is left of `column`.
+fn mirror_differences(fields: &Grid<PatternType>, column: usize) -> usize {
+    (0..column)
+        .rev()
+        .zip(column..fields.width())
+        .map(|(left, right)| {
+            iter::zip(fields.column(left), fields.column(right))
+                .filter(|(f1, f2)| f1 != f2)
+                .count()
+        })
+        .sum()
+}
+
+fn find_mirror(fields: &Grid<PatternType>, smudges: usize) -> Option<usize> {
+    (1..fields.width()).find(|column| mirror_differences(fields, *column) == smudges)
+}
+
+impl Pattern {
+    fn find_mirror_line(&self, smudges: usize) -> MirrorLine {
+        if let Some(index) = find_mirror(&self.fields, smudges) {
+            return MirrorLine::Vertical(index);
+        }
+        if let Some(index) = find_mirror(&self.fields.transpose(), smudges) {
+            return MirrorLine::Horizontal(index);
+        }
+        unreachable!()
+    }
+
+    pub fn get_mirror_line(&self) -> MirrorLine {
+        self.find_mirror_line(0)
+    }
+
+    pub fn get_smudged_mirror_line(&self) -> MirrorLine {
+        self.find_mirror_line(1)
+    }
+}
+
+impl MirrorLine {
+    pub fn get_value(&self) -> usize {
+        match self {
+            MirrorLine::Vertical(line) => *line,
+            MirrorLine::Horizontal(row) => *row * 100,
+        }
+    }
+}
+
+mod parser {
+    use common::error::ParseError;
+    use grid::Grid;
+
+    use crate::PatternType;
+
+    fn get_pattern(input: &str, pattern: &str) -> Result<crate::Pattern, ParseError> {
+        let fields = Grid::parse(
+            pattern,
+            |c| match c {
+                '.' => Some(PatternType::Ash),
+                '#' => Some(PatternType::Rock),
+                _ => None,
+            },
+            "'.' or '#'",
+        )
+        .map_err(|error| error.within(input, pattern))?;
+        Ok(crate::Pattern { fields })
+    }
+    pub(crate) fn parse(input: &str) -> Result<Vec<crate::Pattern>, ParseError> {
+        input
+            .split_terminator("\n\n")
+            .map(|pattern| get_pattern(input, pattern))
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    parser::parse(input)
+}
+
+pub fn part1(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .enumerate()
+        .inspect(|(index, pattern)| trace!(index, ?pattern, "Pattern"))
+        .map(|(index, pattern)| (index, pattern.get_mirror_line()))
+        .inspect(|(index, mirror_line)| debug!(index, ?mirror_line, "Mirror line"))
+        .map(|(_, mirror_line)| mirror_line.get_value())
+        .sum::<usize>()
+}
+
+pub fn part2(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .enumerate()
+        .inspect(|(index, pattern)| trace!(index, ?pattern, "Pattern"))
+        .map(|(index, pattern)| (index, pattern.get_smudged_mirror_line()))
+        .inspect(|(index, mirror_line)| debug!(index, ?mirror_line, "Smudged mirror line"))
+        .map(|(_, mirror_line)| mirror_line.get_value())
+        .sum::<usize>()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Pattern>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(13, "default", Day13);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let patterns = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&patterns), 405);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let patterns = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&patterns), 400);
+    }
+}
@@ -0,0 +1,316 @@
+use common::cycle::Cycle;
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use grid::{
+    render::{Canvas, Colour},
+    Direction, Grid, Position,
+};
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Tile {
+    RoundRock,
+    CubeRock,
+    Space,
+}
+
+impl Tile {
+    fn symbol(&self) -> char {
+        match self {
+            Self::RoundRock => 'O',
+            Self::CubeRock => '#',
+            Self::Space => '.',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    tiles: Grid<Tile>,
+}
+
+impl Debug for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+/// Rolls all round rocks as far up as possible.
+fn roll_north(tiles: &mut Grid<Tile>) {
+    for x in 0..tiles.width() {
+        let mut free_y = 0;
+        for y in 0..tiles.height() {
+            match tiles[Position::new(x, y)] {
+                Tile::CubeRock => free_y = y + 1,
+                Tile::RoundRock => {
+                    tiles[Position::new(x, y)] = Tile::Space;
+                    tiles[Position::new(x, free_y)] = Tile::RoundRock;
+                    free_y += 1;
+                }
+                Tile::Space => (),
+            }
+        }
+    }
+}
+
+impl Platform {
+    pub fn load(&self) -> usize {
+        self.tiles
+            .rows()
+            .enumerate()
+            .map(|(row, tiles)| {
+                let distance = self.tiles.height() - row;
+                let rocks_on_row = tiles
+                    .iter()
+                    .filter(|tile| tile == &&Tile::RoundRock)
+                    .count();
+
+                rocks_on_row * distance
+            })
+            .sum()
+    }
+
+    pub fn tilt(&self, direction: Direction) -> Self {
+        // Rotate the platform such that the tilted side is on top, and back again afterwards.
+        let mut tiles = match direction {
+            Direction::Up => self.tiles.clone(),
+            Direction::Left => self.tiles.rotate_clockwise(),
+            Direction::Down => self.tiles.rotate_clockwise().rotate_clockwise(),
+            Direction::Right => self.tiles.rotate_counterclockwise(),
+        };
+        roll_north(&mut tiles);
+        let tiles = match direction {
+            Direction::Up => tiles,
+            Direction::Left => tiles.rotate_counterclockwise(),
+            Direction::Down => tiles.rotate_clockwise().rotate_clockwise(),
+            Direction::Right => tiles.rotate_clockwise(),
+        };
+
+        Platform { tiles }
+    }
+
+    pub fn spin(&self) -> Self {
+        self.spin_observed(&mut |_| ())
+    }
+
+    /// Like [`Platform::spin`], but shows the platform after each of the four tilts to `observe`.
+    pub fn spin_observed(&self, observe: &mut impl FnMut(&Platform)) -> Self {
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ]
+        .into_iter()
+        .fold(self.clone(), |platform, direction| {
+            let platform = platform.tilt(direction);
+            observe(&platform);
+            platform
+        })
+    }
+}
+
+mod parser {
+    use common::error::ParseError;
+    use grid::Grid;
+
+    use crate::{Platform, Tile};
+
+    pub(crate) fn parse(input: &str) -> Result<Platform, ParseError> {
+        let tiles = Grid::parse(
+            input,
+            |c| match c {
+                'O' => Some(Tile::RoundRock),
+                '#' => Some(Tile::CubeRock),
+                '.' => Some(Tile::Space),
+                _ => None,
+            },
+            "'O', '#' or '.'",
+        )?;
+        Ok(Platform { tiles })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Platform, ParseError> {
+    parser::parse(input)
+}
+
+pub fn part1(platform: &Platform) -> usize {
+    let tilted_platform = platform.tilt(Direction::Up);
+    tilted_platform.load()
+}
+
+/// The platform after `spins` spin cycles, found by detecting when the platforms start repeating.
+/// Every platform after a tilt until the first repetition is shown to `observe`.
+fn spun(platform: &Platform, spins: usize, mut observe: impl FnMut(&Platform)) -> Platform {
+    let cycle = Cycle::find(platform.clone(), |platform| {
+        platform.spin_observed(&mut observe)
+    });
+    cycle.nth(spins).clone()
+}
+
+pub fn load_after_spins(platform: &Platform, spins: usize) -> usize {
+    spun(platform, spins, |_| ()).load()
+}
+
+pub fn part2(platform: &Platform) -> usize {
+    load_after_spins(platform, 1_000_000_000)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Platform;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(14, "default", Day14);
+}
+
+fn draw(platform: &Platform) -> Canvas {
+    let round_rocks = platform
+        .tiles
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::RoundRock)
+        .map(|(position, _)| position);
+    Canvas::new(&platform.tiles, Tile::symbol).overlay(round_rocks, Colour::Yellow, Some('O'))
+}
+
+/// Draws the platform as it is measured in the part, with the round rocks highlighted.
+pub fn render(platform: &Platform, part: u8) -> Canvas {
+    match part {
+        1 => draw(&platform.tilt(Direction::Up)),
+        _ => draw(&spun(platform, 1_000_000_000, |_| ())),
+    }
+}
+
+/// Draws the platform at the start and after every tilt: the one of part 1, or for part 2 the
+/// tilts of all spin cycles until the platform repeats.
+pub fn frames(platform: &Platform, part: u8, mut frame: impl FnMut(Canvas)) {
+    frame(draw(platform));
+    match part {
+        1 => frame(draw(&platform.tilt(Direction::Up))),
+        _ => {
+            spun(platform, 0, |platform| frame(draw(platform)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let platform = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&platform), 136);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let platform = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&platform), 64);
+    }
+
+    #[test]
+    fn demo_any_spins() {
+        let platform = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let mut spinned_platform = platform.clone();
+        for spins in 0..30 {
+            assert_eq!(load_after_spins(&platform, spins), spinned_platform.load());
+            spinned_platform = spinned_platform.spin();
+        }
+    }
+
+    #[test]
+    fn demo_frames() {
+        let platform = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let mut canvases = vec![];
+        frames(&platform, 2, |canvas| canvases.push(canvas.to_string()));
+
+        // The demo platform repeats after 3 + 7 spins.
+        assert_eq!(canvases.len(), 1 + 10 * 4);
+        assert_eq!(canvases[0], platform.tiles.to_string());
+        assert_eq!(canvases[4], platform.spin().tiles.to_string());
+    }
+
+    #[test]
+    fn cycle1_test() {
+        let input = include_str!("../data/demo_input.txt");
+        let platform = parser::parse(input).unwrap();
+
+        let cycle1 = parser::parse(
+            "
+            .....#....
+            ....#...O#
+            ...OO##...
+            .OO#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#....
+            ......OOOO
+            #...O###..
+            #..OO#....",
+        )
+        .unwrap();
+        let spinned_platform = platform.spin();
+        assert_eq!(cycle1.tiles, spinned_platform.tiles);
+
+        let cycle2 = parser::parse(
+            "
+            .....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #..OO###..
+            #.OOO#...O",
+        )
+        .unwrap();
+        let spinned_platform = spinned_platform.spin();
+        assert_eq!(cycle2.tiles, spinned_platform.tiles);
+
+        let cycle3 = parser::parse(
+            "
+            .....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #...O###.O
+            #.OOO#...O",
+        )
+        .unwrap();
+        let spinned_platform = spinned_platform.spin();
+        assert_eq!(cycle3.tiles, spinned_platform.tiles);
+    }
+}
@@ -0,0 +1,161 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Operation {
+    Remove,
+    Add(u8),
+}
+
+#[derive(Debug)]
+pub struct Step<'a> {
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+pub fn hash(input: &str) -> u8 {
+    input
+        .chars()
+        .map(|c| c as u8)
+        .fold(0, |current, c| ((current + c as u32) * 17) % 256) as u8
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .trim_end()
+        .split(',')
+        .map(|sequence| {
+            match sequence.split_once('=') {
+                Some((_, lens)) => {
+                    error::number::<u8>(input, lens)?;
+                }
+                None if sequence.ends_with('-') => (),
+                None => {
+                    let end = &sequence[sequence.len()..];
+                    return Err(ParseError::at(input, end, "'=' or '-'"));
+                }
+            }
+            Ok(sequence)
+        })
+        .collect()
+}
+
+pub fn part1(sequences: &[&str]) -> u32 {
+    sequences.iter().map(|input| hash(input) as u32).sum()
+}
+
+pub fn part2(sequences: &[&str]) -> u32 {
+    let steps = sequences.iter().map(|sequence| {
+        if let Some(equal) = sequence.chars().position(|c| c == '=') {
+            let label = &sequence[..equal];
+            let lens = sequence[equal + 1..].parse::<u8>().unwrap();
+            Step {
+                label,
+                operation: Operation::Add(lens),
+            }
+        } else {
+            let label = &sequence[..sequence.len() - 1];
+            Step {
+                label,
+                operation: Operation::Remove,
+            }
+        }
+    });
+
+    let mut boxes: HashMap<u8, Vec<(&str, u8)>> = HashMap::new();
+    for step in steps {
+        let box_index = hash(step.label);
+
+        if let Some(box_ref) = boxes.get_mut(&box_index) {
+            let pos = box_ref.iter().position(|(label, _)| label == &step.label);
+
+            if let Operation::Add(lens) = step.operation {
+                match pos {
+                    Some(pos) => box_ref[pos] = (step.label, lens),
+                    None => box_ref.push((step.label, lens)),
+                }
+            } else if let Some(pos) = pos {
+                box_ref.remove(pos);
+            }
+        } else if let Operation::Add(lens) = step.operation {
+            boxes.insert(box_index, vec![(step.label, lens)]);
+        }
+    }
+
+    boxes
+        .iter()
+        .flat_map(|(box_index, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(index, (_, lens))| (*box_index as u32, index as u32, *lens as u32))
+        })
+        .map(|(box_index, index, lens)| (box_index + 1) * (index + 1) * lens)
+        .sum()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(15, "default", Day15);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let sequences = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&sequences), 1320);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let sequences = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&sequences), 145);
+    }
+
+    #[test]
+    fn single_entries() {
+        let values = [
+            ("rn=1", 30),
+            ("cm-", 253),
+            ("qp=3", 97),
+            ("cm=2", 47),
+            ("qp-", 14),
+            ("pc=4", 180),
+            ("ot=9", 9),
+            ("ab=5", 197),
+            ("pc-", 48),
+            ("pc=6", 214),
+            ("ot=7", 231),
+        ];
+        for (input, result) in values {
+            let hash_value = hash(input);
+            assert_eq!(hash_value, result);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use grid::{
+    render::{Canvas, Colour},
+    Direction, Grid, Position,
+};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy)]
+pub enum Tiles {
+    Space,
+    Mirror1,
+    Mirror2,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+pub struct Contraption {
+    pub tiles: Grid<Tiles>,
+}
+
+pub fn parse(input: &str) -> Result<Contraption, ParseError> {
+    let tiles = Grid::parse(
+        input,
+        |c| match c {
+            '.' => Some(Tiles::Space),
+            '\\' => Some(Tiles::Mirror1),
+            '/' => Some(Tiles::Mirror2),
+            '-' => Some(Tiles::HorizontalSplitter),
+            '|' => Some(Tiles::VerticalSplitter),
+            _ => None,
+        },
+        "a mirror, a splitter or '.'",
+    )?;
+    Ok(Contraption { tiles })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Beam {
+    pub direction: Direction,
+    pub position: Position,
+}
+
+impl Beam {
+    fn go_on(&self, contraption: &Contraption) -> Option<Self> {
+        let position = contraption.tiles.step(self.position, self.direction)?;
+        Some(Beam { position, ..*self })
+    }
+
+    fn deflect(&self, direction: Direction, contraption: &Contraption) -> Option<Self> {
+        Self { direction, ..*self }.go_on(contraption)
+    }
+}
+
+/// The beams which leave `beam`'s tile.
+fn next_beams(contraption: &Contraption, beam: Beam) -> Vec<Option<Beam>> {
+    match contraption.tiles[beam.position] {
+        Tiles::Space => vec![beam.go_on(contraption)],
+        Tiles::Mirror1 if beam.direction.is_horizontal() => {
+            vec![beam.deflect(beam.direction.turn_right(), contraption)]
+        }
+        Tiles::Mirror1 => vec![beam.deflect(beam.direction.turn_left(), contraption)],
+        Tiles::Mirror2 if beam.direction.is_horizontal() => {
+            vec![beam.deflect(beam.direction.turn_left(), contraption)]
+        }
+        Tiles::Mirror2 => vec![beam.deflect(beam.direction.turn_right(), contraption)],
+        Tiles::HorizontalSplitter if beam.direction.is_horizontal() => {
+            vec![beam.go_on(contraption)]
+        }
+        Tiles::HorizontalSplitter => vec![
+            beam.deflect(Direction::Left, contraption),
+            beam.deflect(Direction::Right, contraption),
+        ],
+        Tiles::VerticalSplitter if !beam.direction.is_horizontal() => {
+            vec![beam.go_on(contraption)]
+        }
+        Tiles::VerticalSplitter => vec![
+            beam.deflect(Direction::Up, contraption),
+            beam.deflect(Direction::Down, contraption),
+        ],
+    }
+}
+
+fn energized_fields(contraption: &Contraption, initial_beam: &Beam) -> HashSet<Position> {
+    energized_fields_observed(contraption, initial_beam, |_, _| ())
+}
+
+/// Propagates all beams by one tile per step. After each step, `observe` is shown the beams
+/// seen so far and the ones which have just moved on to a new tile.
+fn energized_fields_observed(
+    contraption: &Contraption,
+    initial_beam: &Beam,
+    mut observe: impl FnMut(&HashSet<Beam>, &[Beam]),
+) -> HashSet<Position> {
+    let mut beams = vec![*initial_beam];
+
+    let mut energized = HashSet::new();
+    while !beams.is_empty() {
+        beams.retain(|beam| energized.insert(*beam));
+        observe(&energized, &beams);
+        beams = beams
+            .iter()
+            .flat_map(|beam| next_beams(contraption, *beam))
+            .flatten()
+            .collect();
+    }
+
+    energized.iter().map(|beam| beam.position).collect()
+}
+
+pub fn count_energized_fields(contraption: &Contraption, initial_beam: &Beam) -> usize {
+    energized_fields(contraption, initial_beam).len()
+}
+
+fn first_beam() -> Beam {
+    Beam {
+        direction: Direction::Right,
+        position: Position { x: 0, y: 0 },
+    }
+}
+
+pub fn part1(contraption: &Contraption) -> usize {
+    count_energized_fields(contraption, &first_beam())
+}
+
+/// The beams entering the contraption from any edge.
+fn edge_beams(contraption: &Contraption) -> Vec<Beam> {
+    let mut beams = vec![];
+
+    let mut down_beams: Vec<_> = (0..contraption.tiles.width())
+        .map(|x| Beam {
+            position: Position { x, y: 0 },
+            direction: Direction::Down,
+        })
+        .collect();
+    beams.append(&mut down_beams);
+
+    let mut up_beams: Vec<_> = (0..contraption.tiles.width())
+        .map(|x| Beam {
+            position: Position {
+                x,
+                y: contraption.tiles.height() - 1,
+            },
+            direction: Direction::Up,
+        })
+        .collect();
+    beams.append(&mut up_beams);
+
+    let mut left_beams: Vec<_> = (0..contraption.tiles.height())
+        .map(|y| Beam {
+            position: Position {
+                x: contraption.tiles.width() - 1,
+                y,
+            },
+            direction: Direction::Left,
+        })
+        .collect();
+    beams.append(&mut left_beams);
+
+    let mut right_beams: Vec<_> = (0..contraption.tiles.height())
+        .map(|y| Beam {
+            position: Position { x: 0, y },
+            direction: Direction::Right,
+        })
+        .collect();
+    beams.append(&mut right_beams);
+
+    beams
+}
+
+pub fn part2(contraption: &Contraption) -> usize {
+    edge_beams(contraption)
+        .iter()
+        .map(|initial_beam| count_energized_fields(contraption, initial_beam))
+        .max()
+        .unwrap()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Contraption;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(16, "default", Day16);
+}
+
+/// The beam of the part: the first one, or for part 2 the one energizing the most tiles.
+fn part_beam(contraption: &Contraption, part: u8) -> Beam {
+    match part {
+        1 => first_beam(),
+        _ => edge_beams(contraption)
+            .into_iter()
+            .max_by_key(|initial_beam| count_energized_fields(contraption, initial_beam))
+            .unwrap(),
+    }
+}
+
+fn draw(contraption: &Contraption) -> Canvas {
+    Canvas::new(&contraption.tiles, |tile| match tile {
+        Tiles::Space => '.',
+        Tiles::Mirror1 => '\\',
+        Tiles::Mirror2 => '/',
+        Tiles::HorizontalSplitter => '-',
+        Tiles::VerticalSplitter => '|',
+    })
+}
+
+/// Draws the contraption with the tiles energized by the beam of the part highlighted.
+pub fn render(contraption: &Contraption, part: u8) -> Canvas {
+    let energized = energized_fields(contraption, &part_beam(contraption, part));
+    draw(contraption).overlay(energized, Colour::Yellow, None)
+}
+
+/// Draws the energized tiles after every step of the beam of the part, with the tiles the beams
+/// have just reached in red.
+pub fn frames(contraption: &Contraption, part: u8, mut frame: impl FnMut(Canvas)) {
+    let initial_beam = part_beam(contraption, part);
+    energized_fields_observed(contraption, &initial_beam, |energized, front| {
+        let canvas = draw(contraption)
+            .overlay(
+                energized.iter().map(|beam| beam.position),
+                Colour::Yellow,
+                None,
+            )
+            .overlay(front.iter().map(|beam| beam.position), Colour::Red, None);
+        frame(canvas);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let contraption = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&contraption), 46);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let contraption = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&contraption), 51);
+    }
+
+    #[test]
+    fn demo_frames() {
+        let contraption = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let mut canvases = vec![];
+        frames(&contraption, 1, |canvas| canvases.push(canvas));
+
+        let energized = |canvas: &Canvas| {
+            canvas
+                .cells()
+                .iter()
+                .filter(|(_, cell)| cell.colour.is_some())
+                .count()
+        };
+        assert_eq!(energized(&canvases[0]), 1);
+        assert!(canvases
+            .windows(2)
+            .all(|pair| energized(&pair[0]) <= energized(&pair[1])));
+        assert_eq!(energized(canvases.last().unwrap()), 46);
+    }
+}
@@ -0,0 +1,192 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use graph::search::{self, Path};
+use grid::{
+    render::{Canvas, Colour},
+    Direction, Grid, Position,
+};
+use std::str::FromStr;
+
+pub struct CityMap {
+    losses: Grid<Loss>,
+}
+
+pub type Loss = u32;
+
+/// Where the crucible is, where it last moved and how often it did so in a row.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct Crucible {
+    coordinate: Position,
+    last_direction: Option<Direction>,
+    direction_count: u8,
+}
+
+impl FromStr for CityMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let losses = Grid::parse(s, |c| c.to_digit(10).map(|loss| loss as Loss), "a digit")?;
+        Ok(CityMap { losses })
+    }
+}
+
+impl CityMap {
+    pub fn width(&self) -> usize {
+        self.losses.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.losses.height()
+    }
+
+    fn get_neighbours(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Position, Direction)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let neighbour = self.losses.step(position, direction)?;
+            Some((neighbour, direction))
+        })
+    }
+}
+
+/// A lower bound of the loss from `position` to `end`, as every block loses at least 1. Falls
+/// back to 0 when the distance does not fit into a [`Loss`].
+fn heuristic(position: Position, end: Position) -> Loss {
+    position
+        .manhattan_distance(&end)
+        .and_then(|distance| Loss::try_from(distance).ok())
+        .unwrap_or(0)
+}
+
+fn successors<'a>(
+    city_map: &'a CityMap,
+    crucible: Crucible,
+    min_steps: u8,
+    max_steps: u8,
+) -> impl Iterator<Item = (Crucible, Loss)> + 'a {
+    let last_direction = crucible.last_direction;
+    city_map
+        .get_neighbours(crucible.coordinate)
+        .filter(move |(_, direction)| {
+            let straight = last_direction == Some(*direction);
+            last_direction != Some(direction.reverse())
+                && !(straight && crucible.direction_count == max_steps)
+                && (straight || last_direction.is_none() || crucible.direction_count >= min_steps)
+        })
+        .map(move |(successor, direction)| {
+            let direction_count = if last_direction == Some(direction) {
+                crucible.direction_count + 1
+            } else {
+                1
+            };
+            let next = Crucible {
+                coordinate: successor,
+                last_direction: Some(direction),
+                direction_count,
+            };
+            (next, city_map.losses[successor])
+        })
+}
+
+fn cheapest_path(city_map: &CityMap, min_steps: u8, max_steps: u8) -> Path<Crucible, Loss> {
+    let start = Crucible {
+        coordinate: Position { x: 0, y: 0 },
+        last_direction: None,
+        direction_count: 0,
+    };
+    let end = Position {
+        x: city_map.width() - 1,
+        y: city_map.height() - 1,
+    };
+
+    search::astar(
+        start,
+        |crucible| successors(city_map, *crucible, min_steps, max_steps),
+        |crucible| heuristic(crucible.coordinate, end),
+        |crucible| crucible.coordinate == end && crucible.direction_count >= min_steps,
+    )
+    .expect("The crucible cannot reach the factory.")
+}
+
+pub fn minimal_heat_loss(city_map: &CityMap, min_steps: u8, max_steps: u8) -> Loss {
+    cheapest_path(city_map, min_steps, max_steps).cost
+}
+
+pub fn parse(input: &str) -> Result<CityMap, ParseError> {
+    input.parse()
+}
+
+pub fn part1(city_map: &CityMap) -> Loss {
+    minimal_heat_loss(city_map, 1, 3)
+}
+
+pub fn part2(city_map: &CityMap) -> Loss {
+    minimal_heat_loss(city_map, 4, 10)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = CityMap;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(17, "default", Day17);
+}
+
+/// Draws the heat losses with the path of the crucible of the part highlighted.
+pub fn render(city_map: &CityMap, part: u8) -> Canvas {
+    let path = match part {
+        1 => cheapest_path(city_map, 1, 3),
+        _ => cheapest_path(city_map, 4, 10),
+    };
+    Canvas::new(&city_map.losses, |loss| {
+        char::from_digit(*loss, 10).unwrap_or('?')
+    })
+    .overlay(
+        path.states.iter().map(|crucible| crucible.coordinate),
+        Colour::Red,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let city_map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&city_map), 102);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let city_map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&city_map), 94);
+    }
+
+    #[test]
+    fn demo2_part2() {
+        let city_map = parse(include_str!("../data/demo_input2.txt")).unwrap();
+        assert_eq!(part2(&city_map), 71);
+    }
+}
@@ -0,0 +1,188 @@
+use std::str::FromStr;
+
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    geometry::{self, Direction},
+    polygon::Polygon,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+
+#[derive(Debug)]
+pub struct Command {
+    pub length: i64,
+    pub direction: Direction,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut iter = line.split_ascii_whitespace();
+        let direction = match iter.next() {
+            Some("U") => Direction::Up,
+            Some("D") => Direction::Down,
+            Some("L") => Direction::Left,
+            Some("R") => Direction::Right,
+            _ => return Err(ParseError::at(line, line, "'U', 'D', 'L' or 'R'")),
+        };
+
+        let length = match iter.next() {
+            Some(length) => error::number(line, length)?,
+            None => return Err(ParseError::at_end(line, "a length")),
+        };
+
+        Ok(Command { direction, length })
+    }
+}
+
+impl Command {
+    fn from_str2(line: &str) -> Result<Self, ParseError> {
+        let color = line
+            .split_ascii_whitespace()
+            .nth(2)
+            .and_then(|color| color.strip_prefix("(#"))
+            .and_then(|color| color.strip_suffix(')'))
+            .filter(|color| color.len() == 6 && color.is_char_boundary(5))
+            .ok_or_else(|| {
+                let end = line.rfind(' ').map_or(line.len(), |space| space + 1);
+                ParseError::at(line, &line[end..], "a color like \"(#70c710)\"")
+            })?;
+        let length = i64::from_str_radix(&color[..5], 16)
+            .map_err(|_| ParseError::at(line, color, "a hexadecimal length"))?;
+        let direction = match &color[5..] {
+            "3" => Direction::Up,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "0" => Direction::Right,
+            _ => return Err(ParseError::at(line, &color[5..], "a direction from 0 to 3")),
+        };
+        Ok(Command { direction, length })
+    }
+}
+
+type Coordinate = geometry::Coordinate<i64>;
+
+#[derive(Debug)]
+pub struct Commands(pub Vec<Command>);
+
+/// The dig plan read once as written and once from the hexadecimal color codes.
+#[derive(Debug)]
+pub struct Instructions {
+    pub commands: Commands,
+    pub color_commands: Commands,
+}
+
+impl FromStr for Commands {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        error::lines(input).map(Commands)
+    }
+}
+
+impl Commands {
+    fn from_str2(input: &str) -> Result<Self, ParseError> {
+        input
+            .lines()
+            .map(|line| Command::from_str2(line).map_err(|error| error.within(input, line)))
+            .collect::<Result<_, _>>()
+            .map(Commands)
+    }
+}
+
+/// The corners of the trench, which starts and ends at the origin.
+fn get_lagoon(commands: &Commands) -> Polygon {
+    let corners = commands
+        .0
+        .iter()
+        .scan(Coordinate::default(), |position, command| {
+            *position = position
+                .step_by(command.direction, command.length)
+                .expect("Dig plan exceeds the coordinate range.");
+            Some(*position)
+        })
+        .collect::<Vec<_>>();
+
+    assert!(corners.last() == Some(&Coordinate::default()));
+
+    Polygon::new(corners)
+}
+
+/// The number of cubes in the trench and in the interior dug out afterwards.
+fn lagoon_size(commands: &Commands) -> u64 {
+    let lagoon = get_lagoon(commands);
+    lagoon.boundary_points() + lagoon.interior_points()
+}
+
+pub fn parse(input: &str) -> Result<Instructions, ParseError> {
+    Ok(Instructions {
+        commands: Commands::from_str(input)?,
+        color_commands: Commands::from_str2(input)?,
+    })
+}
+
+/// The outline of the lagoon for the dig plan of `part`.
+pub fn lagoon(instructions: &Instructions, part: u8) -> Polygon {
+    match part {
+        1 => get_lagoon(&instructions.commands),
+        _ => get_lagoon(&instructions.color_commands),
+    }
+}
+
+pub fn part1(instructions: &Instructions) -> u64 {
+    lagoon_size(&instructions.commands)
+}
+
+pub fn part2(instructions: &Instructions) -> u64 {
+    lagoon_size(&instructions.color_commands)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Instructions;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(18, "default", Day18);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let instructions = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&instructions), 62);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let instructions = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&instructions), 952408144115);
+    }
+
+    #[test]
+    fn demo_lagoon() {
+        let instructions = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let lagoon = lagoon(&instructions, 1);
+        assert_eq!(lagoon.vertices().len(), 14);
+        assert!(lagoon.is_simple());
+    }
+}
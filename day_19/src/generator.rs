@@ -0,0 +1,81 @@
+//! Random workflows and parts for stress testing.
+
+use std::collections::HashSet;
+
+use common::random::Random;
+
+/// `size` workflows and `size` parts. The workflows form a random tree below `in`, so that
+/// every part ends up accepted or rejected.
+pub fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let names = names(random, size);
+
+    let mut targets = vec![vec![]; size];
+    for child in 1..size {
+        targets[random.index(child)].push(names[child].clone());
+    }
+
+    let mut input = String::new();
+    for (name, mut targets) in names.iter().zip(targets) {
+        for _ in 0..random.index(3) + 1 {
+            targets.push(random.choose(&["A", "R"]).to_string());
+        }
+        random.shuffle(&mut targets);
+        let fallback = targets.pop().expect("Every workflow has a target.");
+        let rules = targets
+            .iter()
+            .map(|target| {
+                let category = random.choose(&['x', 'm', 'a', 's']);
+                let operator = random.choose(&['<', '>']);
+                let value = 1 + random.below(4000);
+                format!("{category}{operator}{value}:{target},")
+            })
+            .collect::<String>();
+        input.push_str(&format!("{name}{{{rules}{fallback}}}\n"));
+    }
+
+    input.push('\n');
+    for _ in 0..size {
+        let [x, m, a, s] = [(); 4].map(|_| 1 + random.below(4000));
+        input.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+    input
+}
+
+/// `in` and further distinct names of two or three letters.
+fn names(random: &mut Random, count: usize) -> Vec<String> {
+    let mut names = vec!["in".to_string()];
+    let mut taken = HashSet::from(["in".to_string()]);
+    while names.len() < count {
+        let length = 2 + random.index(2);
+        let name = (0..length)
+            .map(|_| (b'a' + random.below(26) as u8) as char)
+            .collect::<String>();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use common::total::Total;
+
+    #[test]
+    fn generated_systems() {
+        for seed in 0..20 {
+            let size = 1 + 5 * seed as usize;
+            let input = generate(&mut Random::new(seed), size);
+            assert_eq!(input, generate(&mut Random::new(seed), size));
+
+            let system = parse(&input).unwrap();
+            assert_eq!(system.workflows.0.len(), size);
+            assert_eq!(system.parts.0.len(), size);
+            assert!(part1(&system).unwrap() <= 4 * 4000 * size as Total);
+            assert!(part2(&system).unwrap() <= Total::pow(4000, 4));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+pub mod generator;
+pub mod part;
+pub mod workflow;
+
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::{self, Overflow, Total},
+};
+use std::str::FromStr;
+
+use part::{Part, PossibilityPart};
+
+use crate::part::Parts;
+use crate::workflow::Workflows;
+use workflow::{Condition, Next};
+
+fn eval_condition(condition: &Condition, part: &Part) -> bool {
+    type CompFn = dyn Fn(&u32, &u32) -> bool;
+    let (op, var, val) = match condition {
+        Condition::Less(c, v) => (&u32::lt as &CompFn, c, v),
+        Condition::Greater(c, v) => (&u32::gt as &CompFn, c, v),
+    };
+    let part_value = match var {
+        'x' => part.x,
+        'm' => part.m,
+        'a' => part.a,
+        's' => part.s,
+        _ => unreachable!(),
+    };
+
+    op(&part_value, val)
+}
+
+fn split_part(part: &PossibilityPart, condition: &Condition) -> (PossibilityPart, PossibilityPart) {
+    match condition {
+        Condition::Less(c, v) => part.split_at(part::axis(*c), *v as u16),
+        Condition::Greater(c, v) => {
+            let (else_part, than_part) = part.split_at(part::axis(*c), *v as u16 + 1);
+            (than_part, else_part)
+        }
+    }
+}
+
+fn process_part(part: &Part, workflows: &Workflows, steps: &[Next]) -> bool {
+    let step = &steps[0];
+
+    let next = match step {
+        workflow::Next::Check(condition, next) => {
+            if eval_condition(condition, part) {
+                next
+            } else {
+                return process_part(part, workflows, &steps[1..]);
+            }
+        }
+        workflow::Next::Else(next) => next,
+    };
+
+    match next.as_str() {
+        "A" => true,
+        "R" => false,
+        next_state => process_part(part, workflows, &workflows.0[next_state]),
+    }
+}
+
+fn is_accepted(part: &Part, workflows: &Workflows) -> bool {
+    process_part(part, workflows, &workflows.0["in"])
+}
+
+fn eval_possibilities_workflow(
+    part: &PossibilityPart,
+    workflows: &Workflows,
+    steps: &[Next],
+) -> Vec<PossibilityPart> {
+    if part.is_empty() {
+        return vec![];
+    }
+
+    let step = &steps[0];
+    let next = match step {
+        workflow::Next::Check(condition, next) => {
+            let (than_part, else_part) = split_part(part, condition);
+            let mut possible_parts =
+                eval_possibilities_workflow(&than_part, workflows, &workflows.0[next.as_str()]);
+            possible_parts.append(&mut eval_possibilities_workflow(
+                &else_part,
+                workflows,
+                &steps[1..],
+            ));
+            return possible_parts;
+        }
+        workflow::Next::Else(next) => next,
+    };
+
+    match next.as_str() {
+        "A" => vec![*part],
+        "R" => vec![],
+        _ => eval_possibilities_workflow(part, workflows, &workflows.0[next.as_str()]),
+    }
+}
+
+fn eval_possibilities(workflows: &Workflows) -> Vec<PossibilityPart> {
+    let part = part::all_parts();
+
+    eval_possibilities_workflow(&part, workflows, &workflows.0["in"])
+}
+
+#[derive(Debug)]
+pub struct System {
+    pub workflows: Workflows,
+    pub parts: Parts,
+}
+
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    let (workflow_input, part_input) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "an empty line"))?;
+
+    let workflows = Workflows::from_str(workflow_input)?;
+    let parts = Parts::from_str(part_input).map_err(|error| error.within(input, part_input))?;
+    Ok(System { workflows, parts })
+}
+
+pub fn part1(system: &System) -> Result<Total, Overflow> {
+    total::sum(
+        system
+            .parts
+            .0
+            .iter()
+            .filter(|part| is_accepted(part, &system.workflows))
+            .flat_map(|part| [part.x, part.m, part.a, part.s].map(Total::from)),
+    )
+}
+
+pub fn part2(system: &System) -> Result<Total, Overflow> {
+    let mut workflows = system.workflows.clone();
+
+    workflows
+        .0
+        .insert("A".to_string(), vec![Next::Else("A".to_string())]);
+    workflows
+        .0
+        .insert("R".to_string(), vec![Next::Else("R".to_string())]);
+    let possibilities = eval_possibilities(&workflows);
+
+    possibilities.iter().try_fold(0, |sum, possibility| {
+        let volume = total::product(possibility.axes.iter().map(|axis| Total::from(axis.len())))?;
+        total::add(sum, volume)
+    })
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = System;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(part2(parsed).map(Answer::from))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(19, "default", Day19);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let system = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&system), Ok(19114));
+    }
+
+    #[test]
+    fn demo_part2() {
+        let system = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&system), Ok(167409079868000));
+    }
+}
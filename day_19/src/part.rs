@@ -1,12 +1,13 @@
-use std::{ops::RangeInclusive, str::FromStr};
+use std::str::FromStr;
 
+use common::error::{self, ParseError};
+use interval::{HyperRectangle, Interval};
 use nom::{
-    character::complete::{anychar, char, digit1, newline},
+    character::complete::{char, digit1, one_of},
     combinator::map_res,
-    error::Error,
     multi::separated_list0,
     sequence::{delimited, separated_pair},
-    Finish, IResult,
+    IResult,
 };
 
 #[derive(Default, Debug)]
@@ -17,36 +18,26 @@ pub struct Part {
     pub s: u32,
 }
 
-#[derive(Debug, Clone)]
-pub struct PossibilityPart {
-    pub x: RangeInclusive<u16>,
-    pub m: RangeInclusive<u16>,
-    pub a: RangeInclusive<u16>,
-    pub s: RangeInclusive<u16>,
+/// All parts with ratings in an interval per category, in the order x, m, a, s.
+pub type PossibilityPart = HyperRectangle<u16, 4>;
+
+/// Every possible part, with ratings from 1 to 4000.
+pub fn all_parts() -> PossibilityPart {
+    HyperRectangle::new([Interval::inclusive(1, 4000); 4])
 }
-impl PossibilityPart {
-    pub fn new() -> Self {
-        let range = 1..=4000;
-        PossibilityPart {
-            x: range.clone(),
-            m: range.clone(),
-            a: range.clone(),
-            s: range.clone(),
-        }
-    }
-    pub fn possibilities(&self) -> usize {
-        self.x.len() * self.m.len() * self.a.len() * self.s.len()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.x.is_empty() || self.m.is_empty() || self.a.is_empty() || self.s.is_empty()
-    }
+
+/// The axis of category `c` in a [`PossibilityPart`].
+pub fn axis(c: char) -> usize {
+    "xmas"
+        .find(c)
+        .expect("Categories are parsed with one_of(\"xmas\").")
 }
 
 #[derive(Debug)]
 pub struct Parts(pub Vec<Part>);
 
 fn key_value(input: &str) -> IResult<&str, (char, u32)> {
-    separated_pair(anychar, char('='), map_res(digit1, u32::from_str))(input)
+    separated_pair(one_of("xmas"), char('='), map_res(digit1, u32::from_str))(input)
 }
 
 fn parse_part(input: &str) -> IResult<&str, Part> {
@@ -66,22 +57,18 @@ fn parse_part(input: &str) -> IResult<&str, Part> {
     Ok((input, part))
 }
 
-fn parse_parts(input: &str) -> IResult<&str, Parts> {
-    let (input, parts) = separated_list0(newline, parse_part)(input)?;
+impl FromStr for Part {
+    type Err = ParseError;
 
-    Ok((input, Parts(parts)))
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        error::complete(input, parse_part(input))
+    }
 }
 
 impl FromStr for Parts {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match parse_parts(input).finish() {
-            Ok((_, parts)) => Ok(parts),
-            Err(Error { input, code }) => {
-                eprintln!("input: {input}, error: {:?}", code);
-                Err(())
-            }
-        }
+        error::lines(input).map(Parts)
     }
 }
@@ -1,22 +1,23 @@
 use std::{collections::HashMap, str::FromStr};
 
+use common::error::{self, ParseError};
 use nom::{
     bytes::complete::{take_until, take_while},
     character::complete::{char, digit1, one_of},
-    combinator::map_res,
+    combinator::{all_consuming, map_res},
     error::Error,
     multi::separated_list0,
     sequence::delimited,
-    Finish, IResult,
+    IResult,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Condition {
     Less(char, u32),
     Greater(char, u32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Next {
     Check(Condition, String),
     Else(String),
@@ -25,7 +26,7 @@ pub enum Next {
 #[derive(Debug)]
 pub struct Workflow(String, Vec<Next>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Workflows(pub HashMap<String, Vec<Next>>);
 
 fn parse_condition(input: &str) -> IResult<&str, Condition> {
@@ -42,8 +43,7 @@ fn parse_condition(input: &str) -> IResult<&str, Condition> {
 
 fn parse_next(input: &str) -> IResult<&str, Next> {
     if let Ok((remaining, condition)) = take_until::<&str, &str, Error<_>>(":")(input) {
-        let (input, condition) = parse_condition(condition)?;
-        assert!(input.is_empty());
+        let (_, condition) = all_consuming(parse_condition)(condition)?;
         let (input, _) = char(':')(remaining)?;
         let (input, next_name) = take_while(char::is_alphabetic)(input)?;
         return Ok((input, Next::Check(condition, next_name.to_string())));
@@ -62,25 +62,22 @@ fn parse_workflow(input: &str) -> IResult<&str, Workflow> {
     Ok((input, Workflow(name.to_string(), nexts)))
 }
 
-fn parse_workflows(input: &str) -> IResult<&str, Workflows> {
-    let x = input
-        .lines()
-        .map(|line| parse_workflow(line).unwrap())
-        .map(|(_, workflow)| (workflow.0.to_string(), workflow.1))
-        .collect::<HashMap<_, _>>();
-    Ok(("", Workflows(x)))
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        error::complete(input, parse_workflow(input))
+    }
 }
 
 impl FromStr for Workflows {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match parse_workflows(input).finish() {
-            Ok((_, workflows)) => Ok(workflows),
-            Err(Error { input, code }) => {
-                eprintln!("input: {input}, error: {:?}", code);
-                Err(())
-            }
-        }
+        let workflows = error::lines::<Workflow>(input)?
+            .into_iter()
+            .map(|Workflow(name, nexts)| (name, nexts))
+            .collect();
+        Ok(Workflows(workflows))
     }
 }
@@ -0,0 +1,124 @@
+//! Random module circuits for stress testing.
+
+use std::collections::HashSet;
+
+use common::random::{Random, SizeError};
+
+/// The number of flip-flops in every counter.
+const BITS: usize = 12;
+
+/// The most counters in a circuit. Their periods are below 2^12, so that the least common
+/// multiple of five of them stays below 2^60 and fits in a `u64`.
+const MAX_COUNTERS: usize = 5;
+
+/// A circuit like the real input, with `size` counters, from one up to five. Each counter is a
+/// chain of flip-flops counting button presses, and a conjunction which resets it when it
+/// reaches its period. The counters report to `rx` through a final conjunction, which therefore
+/// first sends a low pulse after the least common multiple of the periods.
+pub fn generate(random: &mut Random, size: usize) -> Result<String, SizeError> {
+    let counters = SizeError::check(size, 1..=MAX_COUNTERS)?;
+    Ok(circuit(random, counters).0)
+}
+
+/// The circuit and the periods of its `counters`.
+fn circuit(random: &mut Random, counters: usize) -> (String, Vec<usize>) {
+    let mut names = names(random, counters * (BITS + 2) + 1).into_iter();
+    let mut name = || names.next().expect("There are enough names.");
+
+    let last = name();
+    let mut lines = vec![format!("&{last} -> rx")];
+    let mut starts = vec![];
+    let mut periods = vec![];
+    for _ in 0..counters {
+        // Odd periods of twelve bits, so that the reset also clears the lowest bit.
+        let period = (1 << (BITS - 1)) | random.index(1 << (BITS - 1)) | 1;
+        let bits = (0..BITS).map(|_| name()).collect::<Vec<_>>();
+        let (reset, report) = (name(), name());
+
+        let mut reset_targets = vec![bits[0].clone()];
+        for (bit, flip_flop) in bits.iter().enumerate() {
+            let mut targets = vec![];
+            if let Some(next) = bits.get(bit + 1) {
+                targets.push(next.clone());
+            }
+            if period & 1 << bit != 0 {
+                targets.push(reset.clone());
+            } else {
+                reset_targets.push(flip_flop.clone());
+            }
+            random.shuffle(&mut targets);
+            lines.push(format!("%{flip_flop} -> {}", targets.join(", ")));
+        }
+        reset_targets.push(report.clone());
+        random.shuffle(&mut reset_targets);
+        lines.push(format!("&{reset} -> {}", reset_targets.join(", ")));
+        lines.push(format!("&{report} -> {last}"));
+
+        starts.push(bits[0].clone());
+        periods.push(period);
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    random.shuffle(&mut lines);
+
+    (lines.join("\n") + "\n", periods)
+}
+
+/// Distinct names of two letters, other than `rx`. There are 675 of them.
+fn names(random: &mut Random, count: usize) -> Vec<String> {
+    assert!(
+        count < 26 * 26,
+        "There are not {count} names of two letters."
+    );
+    let mut names = vec![];
+    let mut taken = HashSet::from(["rx".to_string()]);
+    while names.len() < count {
+        let name = (0..2)
+            .map(|_| (b'a' + random.below(26) as u8) as char)
+            .collect::<String>();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Integer;
+
+    use super::*;
+    use crate::{parse, part1, part2};
+
+    #[test]
+    fn generated_circuits() {
+        for seed in 0..5 {
+            let counters = 1 + seed as usize;
+            let (input, periods) = circuit(&mut Random::new(seed), counters);
+            assert_eq!(
+                generate(&mut Random::new(seed), counters).as_ref(),
+                Ok(&input)
+            );
+
+            let modules = parse(&input).unwrap();
+            assert!(part1(&modules) > 0);
+            let presses = periods.into_iter().reduce(|a, b| a.lcm(&b)).unwrap();
+            assert_eq!(part2(&modules), presses);
+        }
+    }
+
+    #[test]
+    fn size_limits() {
+        for size in [0, MAX_COUNTERS + 1] {
+            let error = generate(&mut Random::new(7), size).unwrap_err();
+            assert_eq!(error.supported, 1..=MAX_COUNTERS);
+        }
+
+        let (input, periods) = circuit(&mut Random::new(7), MAX_COUNTERS);
+        assert_eq!(
+            generate(&mut Random::new(7), MAX_COUNTERS).as_ref(),
+            Ok(&input)
+        );
+        let presses = periods.into_iter().reduce(|a, b| a.lcm(&b)).unwrap();
+        assert_eq!(part2(&parse(&input).unwrap()), presses);
+    }
+}
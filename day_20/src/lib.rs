@@ -0,0 +1,211 @@
+use common::{
+    answer::Answer,
+    assumption::Assumption,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use std::collections::{HashMap, VecDeque};
+
+use graph::NodeId;
+use module::{get_modules, Modules, Signal};
+use num::Integer;
+use tracing::{debug, trace, trace_span};
+
+pub mod generator;
+pub mod module;
+
+fn press_button(modules: &mut Modules, mut on_signal: impl FnMut(NodeId, Signal, NodeId)) {
+    let button = modules.names.get("button").unwrap();
+    let broadcaster = modules.names.get("broadcaster").unwrap();
+    let mut signals = VecDeque::new();
+    signals.push_back((button, Signal::Low, broadcaster));
+
+    while let Some((sender, signal, current_target)) = signals.pop_front() {
+        trace!(
+            from = modules.names.resolve(sender).as_str(),
+            to = modules.names.resolve(current_target).as_str(),
+            ?signal,
+            "Pulse"
+        );
+        on_signal(sender, signal, current_target);
+
+        if let Some(new_signal) = modules.recv_signal(current_target, &signal, sender) {
+            for target in modules.wiring.neighbours(current_target) {
+                signals.push_back((current_target, new_signal, target));
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Modules, ParseError> {
+    get_modules(input)
+}
+
+pub fn part1(modules: &Modules) -> usize {
+    let mut modules = modules.clone();
+
+    let mut signal_count = (0usize, 0usize);
+    for press in 1..=1000 {
+        let _span = trace_span!("press", press).entered();
+        press_button(&mut modules, |_, signal, _| match signal {
+            Signal::High => signal_count.1 += 1,
+            Signal::Low => signal_count.0 += 1,
+        });
+        debug!(
+            press,
+            low = signal_count.0,
+            high = signal_count.1,
+            "Pulses so far"
+        );
+    }
+
+    signal_count.0 * signal_count.1
+}
+
+pub fn part2(modules: &Modules) -> usize {
+    // The "rx"-module is solely dependent on module "cn", which in turn is a conjunction with inputs "th", "sv", "gh" and "ch".
+    // "cn" emits a low signal when all of its four inputs are high. The inputs become high after 3947, 4001, 3943 and 3917 button
+    // presses, respectivally. The least common multiple (lcm) of these four numbers is the solution.
+    let rx = modules.names.get("rx").unwrap();
+    let feeder = modules.wiring.predecessors(rx).next().unwrap();
+
+    first_high_signals(modules, feeder)
+        .expect("The assumptions check that every input sends a high pulse.")
+        .into_iter()
+        .reduce(|prev, count| prev.lcm(&count))
+        .unwrap()
+}
+
+/// The number of presses after which part 2 gives up waiting for an input of the conjunction.
+const MAX_PRESSES: usize = 1 << 16;
+
+/// For each input of `feeder`, the number of button presses until it first sends a high pulse
+/// to `feeder`. `None` if one of them stays silent for `MAX_PRESSES` presses.
+fn first_high_signals(modules: &Modules, feeder: NodeId) -> Option<Vec<usize>> {
+    let mut modules = modules.clone();
+    let input_count = modules.wiring.predecessors(feeder).count();
+
+    let mut first_high_signals = HashMap::new();
+    for button_presses in 1..=MAX_PRESSES {
+        let _span = trace_span!("press", press = button_presses).entered();
+        press_button(&mut modules, |sender, signal, target| {
+            if target == feeder && signal == Signal::High {
+                first_high_signals.entry(sender).or_insert(button_presses);
+            }
+        });
+        for (input, _) in first_high_signals
+            .iter()
+            .filter(|(_, presses)| **presses == button_presses)
+        {
+            debug!(
+                input = modules.names.resolve(*input).as_str(),
+                presses = button_presses,
+                "First high pulse to the conjunction in front of rx"
+            );
+        }
+        if first_high_signals.len() == input_count {
+            return Some(first_high_signals.into_values().collect());
+        }
+    }
+    None
+}
+
+/// The properties of the circuit which let part 2 wait for the inputs of the conjunction in
+/// front of `rx` instead of for `rx` itself.
+pub fn assumptions(modules: &Modules, part: u8) -> Vec<Assumption> {
+    if part == 1 {
+        return Vec::new();
+    }
+    let rx = modules.names.get("rx");
+    let feeders = rx
+        .map(|rx| modules.wiring.predecessors(rx).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let conjunction = match feeders[..] {
+        [feeder] => modules.is_conjunction(feeder),
+        _ => false,
+    };
+    let periodic = conjunction && first_high_signals(modules, feeders[0]).is_some();
+    vec![
+        Assumption::new("There is a module rx.", rx.is_some()),
+        Assumption::new("A single module sends pulses to rx.", feeders.len() == 1),
+        Assumption::new(
+            "The module sending pulses to rx is a conjunction.",
+            conjunction,
+        ),
+        Assumption::new(
+            "Every input of the conjunction sends it a high pulse within 65536 presses.",
+            periodic,
+        ),
+    ]
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Modules;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+
+    fn assumptions(&self, parsed: &Self::Parsed<'_>, part: u8) -> Vec<Assumption> {
+        assumptions(parsed, part)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(20, "default", Day20);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo1_part1() {
+        let modules = parse(include_str!("../data/demo_input1.txt")).unwrap();
+        assert_eq!(part1(&modules), 32000000);
+    }
+
+    #[test]
+    fn demo_assumptions() {
+        let modules = parse(include_str!("../data/demo_input2.txt")).unwrap();
+        let holds = assumptions(&modules, 2)
+            .iter()
+            .map(|assumption| assumption.holds)
+            .collect::<Vec<_>>();
+        // The output of the demo is called "output".
+        assert_eq!(holds, [false, false, false, false]);
+
+        let modules = parse("broadcaster -> a, b\n%a -> rx\n&b -> rx\n").unwrap();
+        let holds = assumptions(&modules, 2)
+            .iter()
+            .map(|assumption| assumption.holds)
+            .collect::<Vec<_>>();
+        assert_eq!(holds, [true, false, false, false]);
+
+        // The flip-flop a never receives a pulse, so it never sends one to the conjunction.
+        let modules = parse("broadcaster -> b\n%a -> c\n%b -> c\n&c -> rx\n").unwrap();
+        let holds = assumptions(&modules, 2)
+            .iter()
+            .map(|assumption| assumption.holds)
+            .collect::<Vec<_>>();
+        assert_eq!(holds, [true, true, true, false]);
+    }
+
+    #[test]
+    fn demo2_part1() {
+        let modules = parse(include_str!("../data/demo_input2.txt")).unwrap();
+        assert_eq!(part1(&modules), 11687500);
+    }
+}
@@ -1,46 +1,75 @@
+use common::error::{self, ParseError};
+use graph::{Graph, Interner, NodeId};
 use nom::{
-    bytes::complete::{tag, take},
-    character::complete::alpha1,
+    bytes::complete::tag,
+    character::complete::{alpha1, one_of},
     multi::separated_list0,
     IResult,
 };
-use std::{any::Any, cell::Cell, collections::HashMap};
+use std::{cell::Cell, collections::HashMap};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Signal {
     High,
     Low,
 }
 
-pub trait AsAny: Any {
-    fn as_any(&self) -> &dyn Any;
-    fn as_mut_any(&mut self) -> &mut dyn Any;
+pub trait Module {
+    /// Handles `signal` and returns the signal sent to all outputs in response, if any.
+    fn recv_signal(&mut self, signal: &Signal, sender: NodeId) -> Option<Signal>;
+    fn connect_input(&mut self, _input: NodeId) {}
+    fn clone_box(&self) -> Box<dyn Module>;
+    fn is_conjunction(&self) -> bool {
+        false
+    }
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
 }
 
-pub trait Module: AsAny {
-    fn recv_signal(&mut self, signal: &Signal, sender: &'static str)
-        -> Vec<(&'static str, Signal)>;
-    fn outputs(&self) -> &[&'static str];
+/// The modules by the IDs of their names, and the wiring from every module to its outputs.
+///
+/// The button is wired to the broadcaster. Outputs without a module, like "rx", only receive.
+#[derive(Clone)]
+pub struct Modules {
+    pub names: Interner,
+    pub wiring: Graph,
+    modules: Vec<Option<Box<dyn Module>>>,
 }
 
-impl<T: Any> AsAny for T {
-    fn as_any(&self) -> &dyn Any {
-        self
+impl Modules {
+    /// Delivers `signal` to `target` and returns the signal it sends to its outputs, if any.
+    pub fn recv_signal(
+        &mut self,
+        target: NodeId,
+        signal: &Signal,
+        sender: NodeId,
+    ) -> Option<Signal> {
+        self.modules
+            .get_mut(target.index())
+            .and_then(Option::as_mut)
+            .and_then(|module| module.recv_signal(signal, sender))
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn Any {
-        self
+    pub fn is_conjunction(&self, id: NodeId) -> bool {
+        self.modules
+            .get(id.index())
+            .and_then(Option::as_ref)
+            .is_some_and(|module| module.is_conjunction())
     }
 }
 
-struct FlipFlop<'a> {
-    outputs: Vec<&'a str>,
+#[derive(Clone)]
+struct FlipFlop {
     is_off: Cell<bool>,
 }
-impl Module for FlipFlop<'static> {
-    fn recv_signal(&mut self, signal: &Signal, _: &'static str) -> Vec<(&'static str, Signal)> {
+impl Module for FlipFlop {
+    fn recv_signal(&mut self, signal: &Signal, _: NodeId) -> Option<Signal> {
         match signal {
-            Signal::High => vec![],
+            Signal::High => None,
             Signal::Low => {
                 self.is_off.set(!self.is_off.get());
                 let new_signal = if self.is_off.get() {
@@ -48,30 +77,23 @@ impl Module for FlipFlop<'static> {
                 } else {
                     Signal::High
                 };
-                self.outputs
-                    .iter()
-                    .map(|output| (*output, new_signal))
-                    .collect::<Vec<_>>()
+                Some(new_signal)
             }
         }
     }
 
-    fn outputs(&self) -> &[&'static str] {
-        &self.outputs
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
     }
 }
 
-struct Conjunction<'a> {
-    outputs: Vec<&'a str>,
-    inputs: HashMap<&'a str, Signal>,
+#[derive(Clone)]
+struct Conjunction {
+    inputs: HashMap<NodeId, Signal>,
 }
-impl Module for Conjunction<'static> {
-    fn recv_signal(
-        &mut self,
-        signal: &Signal,
-        sender: &'static str,
-    ) -> Vec<(&'static str, Signal)> {
-        *self.inputs.get_mut(sender).unwrap() = *signal;
+impl Module for Conjunction {
+    fn recv_signal(&mut self, signal: &Signal, sender: NodeId) -> Option<Signal> {
+        *self.inputs.get_mut(&sender).unwrap() = *signal;
         let new_signal = match self
             .inputs
             .iter()
@@ -81,104 +103,97 @@ impl Module for Conjunction<'static> {
             false => Signal::High,
         };
 
-        self.outputs
-            .iter()
-            .map(|output| (*output, new_signal))
-            .collect()
+        Some(new_signal)
     }
 
-    fn outputs(&self) -> &[&'static str] {
-        &self.outputs
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
     }
-}
-impl<'a> Conjunction<'a> {
-    fn set_inputs(&mut self, inputs: &[&'a str]) {
-        self.inputs = HashMap::from_iter(inputs.iter().map(|name| (*name, Signal::Low)));
+
+    fn connect_input(&mut self, input: NodeId) {
+        self.inputs.insert(input, Signal::Low);
     }
-}
 
-struct Broadcaster<'a> {
-    outputs: Vec<&'a str>,
+    fn is_conjunction(&self) -> bool {
+        true
+    }
 }
-impl Module for Broadcaster<'static> {
-    fn recv_signal(&mut self, signal: &Signal, _: &str) -> Vec<(&'static str, Signal)> {
+
+#[derive(Clone)]
+struct Broadcaster;
+impl Module for Broadcaster {
+    fn recv_signal(&mut self, signal: &Signal, _: NodeId) -> Option<Signal> {
         match signal {
             Signal::High => unreachable!(),
-            Signal::Low => self
-                .outputs
-                .iter()
-                .map(|output| (*output, *signal))
-                .collect::<Vec<_>>(),
+            Signal::Low => Some(*signal),
         }
     }
 
-    fn outputs(&self) -> &[&'static str] {
-        &self.outputs
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
     }
 }
 
-fn parse_module<'a>(input: &'static str) -> IResult<&str, (&'a str, Box<dyn Module>)> {
-    let (input, module_type) = take(1u8)(input)?;
+type NamedModule<'a> = (&'a str, Box<dyn Module>, Vec<&'a str>);
+
+fn parse_module(input: &str) -> IResult<&str, NamedModule<'_>> {
+    let (input, module_type) = one_of("%&b")(input)?;
     let (input, mut name) = alpha1(input)?;
-    let (input, _) = take(4u8)(input)?;
+    let (input, _) = tag(" -> ")(input)?;
     let (input, outputs) = separated_list0(tag(", "), alpha1)(input)?;
 
     let module: Box<dyn Module> = match module_type {
-        "%" => Box::new(FlipFlop {
-            outputs,
+        '%' => Box::new(FlipFlop {
             is_off: Cell::new(true),
         }),
-        "&" => Box::new(Conjunction {
-            outputs,
+        '&' => Box::new(Conjunction {
             inputs: HashMap::<_, _>::new(),
         }),
-        "b" => {
+        'b' => {
             name = "broadcaster";
-            Box::new(Broadcaster { outputs })
+            Box::new(Broadcaster)
         }
         _ => unreachable!(),
     };
 
-    Ok((input, (name, module)))
+    Ok((input, (name, module, outputs)))
 }
 
-pub fn get_modules(input: &'static str) -> HashMap<&'static str, Box<dyn Module>> {
-    let mut modules = input
+pub fn get_modules(input: &str) -> Result<Modules, ParseError> {
+    let named_modules = input
         .lines()
-        .map(|line| parse_module(line).unwrap().1)
-        .collect::<HashMap<_, _>>();
-
-    let conjunctions = modules
-        .iter()
-        .filter_map(|(name, module)| {
-            let module_any = module.as_ref().as_any();
-            if module_any.is::<Conjunction>() {
-                Some(*name)
-            } else {
-                None
-            }
+        .map(|line| {
+            error::complete(line, parse_module(line)).map_err(|error| error.within(input, line))
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut names = Interner::new();
+    let mut wiring = Graph::new();
+    let mut modules = Vec::new();
+    let button = names.intern("button");
+    wiring.add_edge(button, names.intern("broadcaster"), ());
+
+    for (name, module, outputs) in named_modules {
+        let id = names.intern(name);
+        for output in outputs {
+            wiring.add_edge(id, names.intern(output), ());
+        }
+        modules.resize_with(modules.len().max(id.index() + 1), || None);
+        modules[id.index()] = Some(module);
+    }
+    modules.resize_with(names.len(), || None);
 
-    for conjunction in conjunctions {
-        let inputs = modules
-            .iter()
-            .filter_map(|(name, module)| {
-                if module.outputs().contains(&conjunction) {
-                    Some(*name)
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
-
-        let module = modules.get_mut(conjunction).unwrap();
-        let module_any = module.as_mut().as_mut_any();
-        match module_any.downcast_mut::<Conjunction>() {
-            Some(conjunction) => conjunction.set_inputs(&inputs),
-            None => unreachable!(),
+    for input in wiring.nodes() {
+        for output in wiring.neighbours(input) {
+            if let Some(module) = &mut modules[output.index()] {
+                module.connect_input(input);
+            }
         }
     }
 
-    modules
+    Ok(Modules {
+        names,
+        wiring,
+        modules,
+    })
 }
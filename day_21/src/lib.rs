@@ -0,0 +1,436 @@
+use common::{
+    answer::Answer,
+    assumption::Assumption,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use graph::search;
+use grid::{
+    render::{Canvas, Colour},
+    Position,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use map::{Coordinate, Map};
+
+pub mod map;
+
+/// The fewest steps to every field reachable within `steps` steps, passing only fields for
+/// which `filter` holds.
+fn visited_fields(
+    map: &Map,
+    start: Coordinate,
+    steps: usize,
+    mut filter: impl FnMut(&Coordinate) -> bool,
+) -> HashMap<Coordinate, usize> {
+    search::costs(
+        start,
+        |field| {
+            map.get_neighbors(field)
+                .filter(&mut filter)
+                .map(|new_field| (new_field, 1))
+                .collect::<Vec<_>>()
+        },
+        steps,
+    )
+}
+
+/// Counts the fields reachable within `steps` steps by the parity of the fewest steps to them.
+fn get_visited_fields(
+    map: &Map,
+    start: Coordinate,
+    steps: usize,
+    filter: impl FnMut(&Coordinate) -> bool,
+) -> (usize, usize) {
+    let visited_fields = visited_fields(map, start, steps, filter);
+
+    let even_count = visited_fields
+        .values()
+        .filter(|step| step.is_multiple_of(2))
+        .count();
+    let odd_count = visited_fields.len() - even_count;
+
+    (even_count, odd_count)
+}
+
+/// Moves the elf step by step from `start`, passing only fields for which `filter` holds, and
+/// returns the fields where it can be after exactly `steps` steps. After each step, `observe` is
+/// shown the number of steps taken and the fields reached with them.
+fn walk(
+    map: &Map,
+    start: Coordinate,
+    steps: usize,
+    mut filter: impl FnMut(&Coordinate) -> bool,
+    mut observe: impl FnMut(usize, &HashSet<Coordinate>),
+) -> HashSet<Coordinate> {
+    let mut fields = HashSet::from([start]);
+    observe(0, &fields);
+    for step in 1..=steps {
+        fields = fields
+            .iter()
+            .flat_map(|field| map.get_neighbors(field))
+            .filter(&mut filter)
+            .collect();
+        observe(step, &fields);
+    }
+    fields
+}
+
+pub fn reachable_fields(map: &Map, steps: usize) -> usize {
+    let (even_count, odd_count) =
+        get_visited_fields(map, Coordinate { x: 0, y: 0 }, steps, |_| true);
+    if steps.is_multiple_of(2) {
+        even_count
+    } else {
+        odd_count
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::from_str(input)
+}
+
+pub fn part1(map: &Map) -> usize {
+    reachable_fields(map, 64)
+}
+
+const PART2_STEPS: usize = 26501365;
+
+pub fn part2(map: &Map) -> usize {
+    reachable_fields_repeated(map, PART2_STEPS)
+}
+
+/// The properties of the real input which the closed form of part 2 needs.
+pub fn assumptions(map: &Map, part: u8) -> Vec<Assumption> {
+    if part == 1 {
+        return Vec::new();
+    }
+    let (width, height) = (map.width(), map.height());
+    let start = map.start();
+    let plot = |position: Position| !map.is_rock(position);
+    let clear_border = (0..width)
+        .flat_map(|x| [Position::new(x, 0), Position::new(x, height - 1)])
+        .chain((0..height).flat_map(|y| [Position::new(0, y), Position::new(width - 1, y)]))
+        .all(plot);
+    let clear_axes = (0..width)
+        .map(|x| Position::new(x, start.y))
+        .chain((0..height).map(|y| Position::new(start.x, y)))
+        .all(plot);
+
+    let square = width == height;
+    let centred = start == Position::new(width / 2, height / 2);
+    vec![
+        Assumption::new("The map is square.", square),
+        Assumption::new("The map has an odd width.", width % 2 == 1),
+        Assumption::new("The start is in the centre of the map.", centred),
+        Assumption::new(
+            "There are no rocks in the row and column of the start.",
+            clear_axes,
+        ),
+        Assumption::new("There are no rocks on the border of the map.", clear_border),
+        Assumption::new(
+            "The steps end at the border of a repeated map.",
+            width > 0 && PART2_STEPS % width == width / 2,
+        ),
+        Assumption::new(
+            "No detour around rocks crosses the diamond of half the width around the start or a corner.",
+            square && centred && detours_within_diamonds(map),
+        ),
+    ]
+}
+
+/// Whether the shortest paths within the map from the start and from the corners reach every
+/// plot on the same side of the diamond of half the width of the map as its Manhattan distance.
+/// The paths may take detours around rocks otherwise.
+fn detours_within_diamonds(map: &Map) -> bool {
+    let map_half = (map.width() / 2) as i32;
+    let in_bounds = |coordinate: &Coordinate| {
+        (-map_half..=map_half).contains(&coordinate.x)
+            && (-map_half..=map_half).contains(&coordinate.y)
+    };
+    let inside = |distance: usize| distance <= map_half as usize;
+    let origins = [(0, 0), (-1, -1), (-1, 1), (1, -1), (1, 1)]
+        .map(|(x, y)| Coordinate::new(x * map_half, y * map_half));
+    origins.into_iter().all(|origin| {
+        visited_fields(map, origin, 2 * map.width(), in_bounds)
+            .into_iter()
+            .all(|(field, steps)| {
+                let manhattan = field.x.abs_diff(origin.x) + field.y.abs_diff(origin.y);
+                inside(steps) == inside(manhattan as usize)
+            })
+    })
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Map;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+
+    fn assumptions(&self, parsed: &Self::Parsed<'_>, part: u8) -> Vec<Assumption> {
+        assumptions(parsed, part)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(21, "default", Day21);
+}
+
+/// The same as [`reachable_fields`], but computed from the fields of a single map, for step
+/// counts which end at the border of a repeated map.
+fn reachable_fields_repeated(map: &Map, steps: usize) -> usize {
+    // part II, works only with real input (The provided demo input can be verified with the updated solution of part 1)
+    // Solution 1 works also for small step count. This can be used to verify that both solutions return the same result.
+    // Some observations by analyzing at the input:
+    //   - The start point is in the center of the map
+    //   - There are no rock-tiles on the horizontal and vertical lines through the start point
+    //   - There are no rock-tiles around the non-repeated map
+    //   - Not every plot-tile is reachable!
+    //   - The detours around rocks are short, so they do not change which plots are reached within width/2 steps from the start or a corner
+    //   => The fastest way to go from the start point to a corner of a single map is to go straight in one direction and turn 90° and go straight in that direction. It takes width/2 + height/2 steps. This works for all 4 corners.
+    //   => The neighbor (repeated) maps are reached after width/2 or height/2 moves, respectivally. We start their exploration in the middle of one of their borders. After (width + height/2) or (height + width/2) moves, the complete map has been visited
+    //   => The repeated maps diagonally of the start map are explored from a corner. To visit all fields on the map, it takes (width + height) moves.
+    //   => For all other repeated maps, either of the last two possibilities applies.
+
+    assert!(
+        map.height() == map.width(),
+        "Solution expects quadratic map."
+    );
+    assert!(
+        (steps - map.width() / 2).is_multiple_of(map.width()),
+        "Special condition not fulfilled for this step-count."
+    );
+
+    let map_half = (map.width() / 2) as i32;
+    let corners = [
+        Coordinate {
+            x: -map_half,
+            y: map_half,
+        },
+        Coordinate {
+            x: map_half,
+            y: map_half,
+        },
+        Coordinate {
+            x: map_half,
+            y: -map_half,
+        },
+        Coordinate {
+            x: -map_half,
+            y: -map_half,
+        },
+    ];
+    let subtract_corners = corners
+        .iter()
+        .map(|corner| {
+            get_visited_fields(map, *corner, map.width() / 2, |coordinate: &Coordinate| {
+                let abs_x = coordinate.x.abs();
+                let abs_y = coordinate.y.abs();
+                let distance = abs_x + abs_y;
+                distance > map_half
+                    && abs_x > 0
+                    && abs_x <= map_half
+                    && abs_y > 0
+                    && abs_y <= map_half
+            })
+        })
+        .fold((0, 0), |sum, corner| (sum.0 + corner.0, sum.1 + corner.1));
+
+    let additive_corners = corners
+        .iter()
+        .map(|corner| {
+            let sign_x = corner.x.signum();
+            let sign_y = corner.y.signum();
+            get_visited_fields(map, *corner, map.width() / 2, |coordinate: &Coordinate| {
+                let abs_x = coordinate.x.abs();
+                let abs_y = coordinate.y.abs();
+                let distance = abs_x + abs_y;
+                distance >= map_half
+                    && sign_x * coordinate.x >= 0
+                    && abs_x <= map_half
+                    && sign_y * coordinate.y >= 0
+                    && abs_y <= map_half
+            })
+        })
+        .fold((0, 0), |sum, corner| (sum.0 + corner.0, sum.1 + corner.1));
+
+    let in_bounds = |coordinate: &Coordinate| {
+        (-map_half..=map_half).contains(&coordinate.x)
+            && (-map_half..=map_half).contains(&coordinate.y)
+    };
+
+    let (total_fields_even, total_fields_odd) =
+        get_visited_fields(map, Coordinate { x: 0, y: 0 }, map.width(), in_bounds);
+    let n = (steps - map.width() / 2) / map.width();
+    let x1 = n.pow(2);
+    let x2 = (n + 1).pow(2);
+
+    let total_covered_maps = 2 * n * n + 2 * n + 1;
+    assert_eq!(total_covered_maps, x1 + x2);
+    // The copies alternate in parity, and those with the parity of the start map count the
+    // fields of the same parity as the steps.
+    let more_evens = n % 2 == steps % 2;
+
+    let total_covered_even_maps = if more_evens { x2 } else { x1 };
+    let total_covered_odd_maps = if more_evens { x1 } else { x2 };
+    let total_additive_corners = if more_evens {
+        additive_corners.1
+    } else {
+        additive_corners.0
+    };
+    let total_subtractive_corners = if more_evens {
+        subtract_corners.0
+    } else {
+        subtract_corners.1
+    };
+    total_covered_even_maps * total_fields_even + total_covered_odd_maps * total_fields_odd
+        - (n + 1) * total_subtractive_corners
+        + n * total_additive_corners
+}
+
+/// Draws the map with the plots highlighted where the elf can end up. For part 2 that is
+/// the pattern on every fully explored copy of the map with the same parity as the start map.
+pub fn render(map: &Map, part: u8) -> Canvas {
+    let (steps, parity, map_half) = match part {
+        1 => (64, 0, i32::MAX),
+        _ => (map.width(), 1, (map.width() / 2) as i32),
+    };
+    let in_bounds = |coordinate: &Coordinate| {
+        (-map_half..=map_half).contains(&coordinate.x)
+            && (-map_half..=map_half).contains(&coordinate.y)
+    };
+    let reachable = visited_fields(map, Coordinate { x: 0, y: 0 }, steps, in_bounds)
+        .into_iter()
+        .filter(|(_, step)| step % 2 == parity)
+        .filter_map(|(field, _)| map.position(&field));
+
+    map.canvas().overlay(reachable, Colour::Green, Some('O'))
+}
+
+/// Draws the plots where the elf can be after every step, for part 1 up to 64 steps and for
+/// part 2 while it explores the original map.
+pub fn frames(map: &Map, part: u8, mut frame: impl FnMut(Canvas)) {
+    let (steps, map_half) = match part {
+        1 => (64, i32::MAX),
+        _ => (map.width(), (map.width() / 2) as i32),
+    };
+    let in_bounds = |coordinate: &Coordinate| {
+        (-map_half..=map_half).contains(&coordinate.x)
+            && (-map_half..=map_half).contains(&coordinate.y)
+    };
+    walk(
+        map,
+        Coordinate { x: 0, y: 0 },
+        steps,
+        in_bounds,
+        |_, fields| {
+            let reachable = fields.iter().filter_map(|field| map.position(field));
+            frame(map.canvas().overlay(reachable, Colour::Green, Some('O')));
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use common::assumption;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Square maps of odd width with the start in the centre, and no rocks on the border or on
+    /// the row and column through the start, like the real input.
+    fn open_map() -> impl Strategy<Value = String> {
+        (2usize..=6).prop_flat_map(|half| {
+            let width = 2 * half + 1;
+            prop::collection::vec(prop::bool::weighted(0.25), width * width).prop_map(
+                move |rocks| {
+                    let open = [0, half, width - 1];
+                    let mut input = String::new();
+                    for y in 0..width {
+                        for x in 0..width {
+                            input.push(match rocks[y * width + x] {
+                                _ if (x, y) == (half, half) => 'S',
+                                true if !open.contains(&x) && !open.contains(&y) => '#',
+                                _ => '.',
+                            });
+                        }
+                        input.push('\n');
+                    }
+                    input
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn repeated_map_by_brute_force(input in open_map(), repeats in 1usize..=3) {
+            let map = parse(&input).unwrap();
+            // The number of steps of the real input does not fit these small maps.
+            let failed = assumption::failed(assumptions(&map, 2));
+            prop_assume!(failed.len() == 1);
+            let steps = map.width() / 2 + repeats * map.width();
+            prop_assert_eq!(
+                reachable_fields_repeated(&map, steps),
+                reachable_fields(&map, steps)
+            );
+        }
+    }
+
+    #[test]
+    fn demo_steps() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(reachable_fields(&map, 6), 16);
+        assert_eq!(reachable_fields(&map, 10), 50);
+        assert_eq!(reachable_fields(&map, 50), 1594);
+        assert_eq!(reachable_fields(&map, 100), 6536);
+    }
+
+    #[test]
+    fn demo_assumptions() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert!(assumptions(&map, 1).is_empty());
+        let failed = assumption::failed(assumptions(&map, 2))
+            .into_iter()
+            .map(|assumption| assumption.description)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            failed,
+            [
+                "There are no rocks in the row and column of the start.",
+                "The steps end at the border of a repeated map.",
+                "No detour around rocks crosses the diamond of half the width around the start or a corner.",
+            ]
+        );
+    }
+
+    #[test]
+    fn demo_walk() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        for steps in 0..20 {
+            let fields = walk(&map, Coordinate { x: 0, y: 0 }, steps, |_| true, |_, _| ());
+            assert_eq!(fields.len(), reachable_fields(&map, steps));
+        }
+
+        let mut canvases = vec![];
+        frames(&map, 1, |canvas| canvases.push(canvas));
+        assert_eq!(canvases.len(), 65);
+        assert_eq!(canvases[0].to_string().matches('O').count(), 1);
+    }
+}
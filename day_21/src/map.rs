@@ -1,3 +1,9 @@
+use common::error::ParseError;
+use common::geometry;
+use grid::{
+    render::{Canvas, Colour},
+    Direction, Grid, Position,
+};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy)]
@@ -6,124 +12,101 @@ enum Tile {
     Rock,
 }
 
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-pub struct Coordinate {
-    pub x: i16,
-    pub y: i16,
-}
-
-impl Coordinate {
-    fn next(&self, direction: &Direction) -> Self {
-        let (x, y) = match direction {
-            Direction::North => (self.x, self.y - 1),
-            Direction::East => (self.x + 1, self.y),
-            Direction::South => (self.x, self.y + 1),
-            Direction::West => (self.x - 1, self.y),
-        };
-        Self { x, y }
-    }
-}
+/// Position relative to the start, on the infinitely repeated map.
+pub type Coordinate = geometry::Coordinate<i32>;
 
 #[derive(Debug)]
 pub struct Map {
-    tiles: Vec<Tile>,
-    pub width: usize,
-    pub height: usize,
+    tiles: Grid<Tile>,
+    start: Position,
 }
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut tiles = input
-            .chars()
-            .filter_map(|c| match c {
-                '.' | 'S' => Some(Tile::Plot),
-                '#' => Some(Tile::Rock),
-                _ => None,
-            })
-            .collect::<Vec<_>>();
-        let width = input.find(|c| c == '\n').unwrap();
-        let height = tiles.len() / width;
-
-        let start_index = input
-            .chars()
-            .filter(|c| c.is_ascii_graphic())
-            .position(|c| c == 'S')
-            .unwrap();
-        let shift_up = start_index / width;
-        let shift_left = start_index % width;
-        tiles.rotate_left(shift_up * width);
-        for row in 0..height {
-            tiles[row * width..(row + 1) * width].rotate_left(shift_left);
-        }
+        let mut start = None;
+        let mut position = 0;
+        let tiles: Grid<Tile> = Grid::parse(
+            input,
+            |c| {
+                position += 1;
+                match c {
+                    '.' => Some(Tile::Plot),
+                    'S' => {
+                        start.get_or_insert(position - 1);
+                        Some(Tile::Plot)
+                    }
+                    '#' => Some(Tile::Rock),
+                    _ => None,
+                }
+            },
+            "'.', '#' or 'S'",
+        )?;
+        let start = start.ok_or_else(|| ParseError::at_end(input, "a start 'S'"))?;
 
         Ok(Map {
+            start: Position::new(start % tiles.width(), start / tiles.width()),
             tiles,
-            width,
-            height,
         })
     }
 }
 
-pub struct NeighbourIterator<'a> {
-    field: Coordinate,
-    current: u8,
-    map: &'a Map,
-}
+impl Map {
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
 
-impl<'a> Iterator for NeighbourIterator<'a> {
-    type Item = Coordinate;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        while self.current < 4 {
-            self.current += 1;
-            let direction = match self.current {
-                1 => Direction::North,
-                2 => Direction::East,
-                3 => Direction::South,
-                4 => Direction::West,
-                _ => unreachable!(),
-            };
-            let new_coordinate = self.field.next(&direction);
-            let tile = self.map.get_tile(&new_coordinate);
-            match tile {
-                Tile::Plot => return Some(new_coordinate),
-                Tile::Rock => {}
-            }
-        }
-        None
+    pub fn height(&self) -> usize {
+        self.tiles.height()
     }
-}
 
-impl Map {
-    pub fn get_neighbors(&self, field: &Coordinate) -> NeighbourIterator {
-        NeighbourIterator {
-            field: *field,
-            current: 0,
-            map: self,
-        }
+    pub fn start(&self) -> Position {
+        self.start
     }
 
-    fn get_tile(&self, field: &Coordinate) -> Tile {
-        let wrapped_field = self.wrap(field);
-        // let index = (y * self.width as i32 + x).rem_euclid(self.tiles.len() as i32) as usize;
+    /// Whether the tile at `position` of the original map is a rock.
+    pub fn is_rock(&self, position: Position) -> bool {
+        matches!(self.tiles[position], Tile::Rock)
+    }
+
+    pub fn get_neighbors(&self, field: &Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        let field = *field;
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| field.step(direction))
+            .filter(|neighbour| matches!(self.get_tile(neighbour), Tile::Plot))
+    }
+
+    /// The position of `field` on the original map, unless it lies on one of the copies.
+    pub fn position(&self, field: &Coordinate) -> Option<Position> {
+        let position = Coordinate::new(
+            field.x.checked_add(self.start.x.try_into().ok()?)?,
+            field.y.checked_add(self.start.y.try_into().ok()?)?,
+        );
+        position
+            .try_convert()
+            .filter(|position| self.tiles.contains(*position))
+    }
 
-        let index = (wrapped_field.y * self.width as i16 + wrapped_field.x) as usize;
+    /// Draws the original map, with the start as 'S'.
+    pub fn canvas(&self) -> Canvas {
+        let canvas = Canvas::new(&self.tiles, |tile| match tile {
+            Tile::Plot => '.',
+            Tile::Rock => '#',
+        });
+        canvas.overlay([self.start], Colour::Yellow, Some('S'))
+    }
 
-        self.tiles[index]
+    fn get_tile(&self, field: &Coordinate) -> Tile {
+        self.tiles[self.wrap(field)]
     }
-    fn wrap(&self, field: &Coordinate) -> Coordinate {
-        let x = field.x.rem_euclid(self.width as i16);
-        let y = field.y.rem_euclid(self.height as i16);
-        Coordinate { x, y }
+
+    fn wrap(&self, field: &Coordinate) -> Position {
+        self.tiles.wrap(
+            field.x as i64 + self.start.x as i64,
+            field.y as i64 + self.start.y as i64,
+        )
     }
 }
 
@@ -134,19 +117,18 @@ mod tests {
     #[test]
     fn wrap_tests() {
         let map = Map {
-            height: 11,
-            width: 9,
-            tiles: vec![Tile::Plot; 11 * 9],
+            tiles: Grid::filled(9, 11, Tile::Plot),
+            start: Position::default(),
         };
         let coordinates = [
-            (Coordinate { x: 0, y: 0 }, Coordinate { x: 0, y: 0 }),
-            (Coordinate { x: -9, y: 0 }, Coordinate { x: 0, y: 0 }),
-            (Coordinate { x: 0, y: -11 }, Coordinate { x: 0, y: 0 }),
-            (Coordinate { x: 9, y: 0 }, Coordinate { x: 0, y: 0 }),
-            (Coordinate { x: 0, y: 11 }, Coordinate { x: 0, y: 0 }),
-            (Coordinate { x: -1, y: 0 }, Coordinate { x: 8, y: 0 }),
-            (Coordinate { x: 1, y: 0 }, Coordinate { x: 1, y: 0 }),
-            (Coordinate { x: -1, y: -1 }, Coordinate { x: 8, y: 10 }),
+            (Coordinate { x: 0, y: 0 }, Position { x: 0, y: 0 }),
+            (Coordinate { x: -9, y: 0 }, Position { x: 0, y: 0 }),
+            (Coordinate { x: 0, y: -11 }, Position { x: 0, y: 0 }),
+            (Coordinate { x: 9, y: 0 }, Position { x: 0, y: 0 }),
+            (Coordinate { x: 0, y: 11 }, Position { x: 0, y: 0 }),
+            (Coordinate { x: -1, y: 0 }, Position { x: 8, y: 0 }),
+            (Coordinate { x: 1, y: 0 }, Position { x: 1, y: 0 }),
+            (Coordinate { x: -1, y: -1 }, Position { x: 8, y: 10 }),
         ];
 
         for (actual, expected) in coordinates {
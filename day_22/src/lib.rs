@@ -0,0 +1,346 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use grid::{
+    render::{Canvas, Colour},
+    Grid, Position,
+};
+use interval::Interval;
+use multimap::MultiMap;
+use std::{collections::HashSet, str::FromStr};
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone)]
+pub struct Cube {
+    pub x: Interval<i32>,
+    pub y: Interval<i32>,
+    pub z: Interval<i32>,
+
+    supported_by: Vec<usize>,
+}
+
+fn corner(line: &str, text: &str) -> Result<[i32; 3], ParseError> {
+    let numbers = text
+        .split(',')
+        .map(|number| error::number(line, number))
+        .collect::<Result<Vec<_>, _>>()?;
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::at(line, text, "three coordinates"))
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (start, end) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::at_end(line, "'~'"))?;
+        let [x1, y1, z1] = corner(line, start)?;
+        let [x2, y2, z2] = corner(line, end)?;
+        if x1 > x2 || y1 > y2 || z1 > z2 {
+            return Err(ParseError::at(line, end, "an end not before the start"));
+        }
+
+        Ok(Cube {
+            x: Interval::inclusive(x1, x2),
+            y: Interval::inclusive(y1, y2),
+            z: Interval::inclusive(z1, z2),
+            supported_by: vec![],
+        })
+    }
+}
+
+fn fall(cubes: &[Cube]) -> Vec<Cube> {
+    fall_observed(cubes, |_, _| ())
+}
+
+/// Lets the cubes fall one after the other, from the lowest up. After each cube has come to
+/// rest, `observe` is shown the fallen cubes and the ones still to fall.
+fn fall_observed(cubes: &[Cube], mut observe: impl FnMut(&[Cube], &[Cube])) -> Vec<Cube> {
+    let mut cubes = cubes.to_vec();
+    cubes.sort_unstable_by_key(|cube| cube.z.start());
+
+    let mut fallen_cubes: Vec<Cube> = Vec::new();
+    for (index, cube) in cubes.iter().enumerate() {
+        let supported_by = fallen_cubes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, fallen_cube)| {
+                if cube.x.intersects(&fallen_cube.x) && cube.y.intersects(&fallen_cube.y) {
+                    Some(index)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The lowest free level, right above the highest cube below or the ground.
+        let bottom = supported_by
+            .iter()
+            .map(|c| fallen_cubes[*c].z.end())
+            .max()
+            .unwrap_or(1);
+
+        let new_cube = Cube {
+            z: Interval::new(bottom, bottom + cube.z.len() as i32),
+            supported_by,
+            ..cube.clone()
+        };
+        let supported_by = new_cube
+            .supported_by
+            .iter()
+            .filter(|index| fallen_cubes[**index].z.end() == new_cube.z.start())
+            .cloned()
+            .collect::<Vec<_>>();
+        let new_cube = Cube {
+            supported_by,
+            ..new_cube
+        };
+        trace!(index, cube = ?new_cube, "Cube came to rest");
+        fallen_cubes.push(new_cube);
+        observe(&fallen_cubes, &cubes[index + 1..]);
+    }
+    debug!(
+        cubes = fallen_cubes.len(),
+        height = fallen_cubes.iter().map(|cube| cube.z.end()).max(),
+        "All cubes have fallen"
+    );
+    fallen_cubes
+}
+
+fn get_needed_cubes(fallen_cubes: &[Cube]) -> HashSet<usize> {
+    fallen_cubes
+        .iter()
+        .filter_map(|cube| match cube.supported_by.len() {
+            1 => Some(cube.supported_by.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect::<HashSet<_>>()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cube>, ParseError> {
+    error::lines(input)
+}
+
+pub fn part1(cubes: &[Cube]) -> usize {
+    let fallen_cubes = fall(cubes);
+    let needed_cubes = get_needed_cubes(&fallen_cubes);
+
+    fallen_cubes.len() - needed_cubes.len()
+}
+
+/// For every cube the cubes resting directly on it.
+fn get_supports(fallen_cubes: &[Cube]) -> MultiMap<usize, usize> {
+    fallen_cubes
+        .iter()
+        .enumerate()
+        .flat_map(|(index, cube)| {
+            cube.supported_by
+                .iter()
+                .map(move |support| (*support, index))
+        })
+        .collect()
+}
+
+/// The cubes that fall when the cube at `index` is removed.
+fn falling_cubes(
+    fallen_cubes: &[Cube],
+    supports: &MultiMap<usize, usize>,
+    index: usize,
+) -> HashSet<usize> {
+    let mut could_falls = supports.get_vec(&index).cloned().unwrap_or_default();
+    let mut removed = HashSet::new();
+    removed.insert(index);
+    while let Some(could_fall) = could_falls.pop() {
+        let cube = &fallen_cubes[could_fall];
+        if cube
+            .supported_by
+            .iter()
+            .all(|index| removed.contains(index))
+        {
+            removed.insert(could_fall);
+            could_falls.append(&mut supports.get_vec(&could_fall).unwrap_or(&Vec::new()).clone());
+        }
+    }
+    removed.remove(&index);
+    removed
+}
+
+pub fn part2(cubes: &[Cube]) -> usize {
+    let fallen_cubes = fall(cubes);
+    let needed_cubes = get_needed_cubes(&fallen_cubes);
+    let supports = get_supports(&fallen_cubes);
+
+    needed_cubes
+        .iter()
+        .map(|index| falling_cubes(&fallen_cubes, &supports, *index).len())
+        .sum::<usize>()
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Vec<Cube>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(22, "default", Day22);
+}
+
+/// For every cell of the front view, with `x` to the right and `z` upwards up to `height`, the
+/// index of the frontmost cube covering it.
+fn front_view(cubes: &[Cube], width: i32, height: i32) -> Grid<Option<usize>> {
+    let mut front = Grid::filled(width.max(0) as usize, height.max(0) as usize, None);
+
+    let mut by_depth = (0..cubes.len()).collect::<Vec<_>>();
+    by_depth.sort_unstable_by_key(|index| std::cmp::Reverse(cubes[*index].y.start()));
+    for index in by_depth {
+        let cube = &cubes[index];
+        for x in cube.x.start()..cube.x.end() {
+            for z in cube.z.start()..cube.z.end() {
+                let position = Position::new(x as usize, (height - z) as usize);
+                if let Some(cell) = front.get_mut(position) {
+                    *cell = Some(index);
+                }
+            }
+        }
+    }
+    front
+}
+
+fn width(cubes: &[Cube]) -> i32 {
+    cubes.iter().map(|cube| cube.x.end()).max().unwrap_or(0)
+}
+
+fn height(cubes: &[Cube]) -> i32 {
+    cubes.iter().map(|cube| cube.z.end() - 1).max().unwrap_or(0)
+}
+
+fn draw(front: &Grid<Option<usize>>) -> Canvas {
+    Canvas::new(front, |index| if index.is_some() { '#' } else { '.' })
+}
+
+/// The positions in the front view which show one of `cubes`.
+fn positions(front: &Grid<Option<usize>>, cubes: &HashSet<usize>) -> Vec<Position> {
+    front
+        .iter()
+        .filter(|(_, index)| index.is_some_and(|index| cubes.contains(&index)))
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// The fallen cubes seen from the front, with `x` to the right and `z` upwards. For part 1 the
+/// cubes which can be disintegrated safely are green and the needed ones red. For part 2 the
+/// cube whose removal makes the most others fall is red and the falling ones yellow.
+pub fn render(cubes: &[Cube], part: u8) -> Canvas {
+    let fallen_cubes = fall(cubes);
+    let front = front_view(&fallen_cubes, width(&fallen_cubes), height(&fallen_cubes));
+    let canvas = draw(&front);
+    let needed_cubes = get_needed_cubes(&fallen_cubes);
+    match part {
+        1 => {
+            let safe_cubes = (0..fallen_cubes.len())
+                .filter(|index| !needed_cubes.contains(index))
+                .collect();
+            canvas
+                .overlay(positions(&front, &safe_cubes), Colour::Green, None)
+                .overlay(positions(&front, &needed_cubes), Colour::Red, None)
+        }
+        _ => {
+            let supports = get_supports(&fallen_cubes);
+            let Some((index, falling)) = needed_cubes
+                .iter()
+                .map(|index| (*index, falling_cubes(&fallen_cubes, &supports, *index)))
+                .max_by_key(|(index, falling)| (falling.len(), std::cmp::Reverse(*index)))
+            else {
+                return canvas;
+            };
+            canvas
+                .overlay(positions(&front, &falling), Colour::Yellow, None)
+                .overlay(
+                    positions(&front, &HashSet::from([index])),
+                    Colour::Red,
+                    None,
+                )
+        }
+    }
+}
+
+/// Draws the cubes from the front after each one has fallen, the cubes still to fall in cyan and
+/// the one which has just come to rest in red. Both parts start with the same fall.
+pub fn frames(cubes: &[Cube], _part: u8, mut frame: impl FnMut(Canvas)) {
+    let (width, height) = (width(cubes), height(cubes));
+    fall_observed(cubes, |fallen_cubes, falling_cubes| {
+        let all_cubes = [fallen_cubes, falling_cubes].concat();
+        let front = front_view(&all_cubes, width, height);
+        let falling = (fallen_cubes.len()..all_cubes.len()).collect();
+        let landed = HashSet::from([fallen_cubes.len() - 1]);
+        let canvas = draw(&front)
+            .overlay(positions(&front, &falling), Colour::Cyan, None)
+            .overlay(positions(&front, &landed), Colour::Red, None);
+        frame(canvas);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let cubes = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&cubes), 5);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let cubes = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&cubes), 7);
+    }
+
+    #[test]
+    fn demo_render() {
+        let cubes = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let canvas = render(&cubes, 1);
+        // Without colours the safe cubes are marked as O, and the needed ones as #.
+        assert_eq!(canvas.to_string(), ".O.\n.O.\n###\nO.O\nOOO\n.#.\n");
+        let red = canvas
+            .cells()
+            .iter()
+            .filter(|(_, cell)| cell.colour == Some(Colour::Red))
+            .count();
+        // Brick A at the bottom and brick F, on which G rests alone.
+        assert_eq!(red, 1 + 3);
+    }
+
+    #[test]
+    fn demo_frames() {
+        let cubes = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let mut canvases = vec![];
+        frames(&cubes, 1, |canvas| canvases.push(canvas.to_string()));
+
+        assert_eq!(canvases.len(), cubes.len());
+        // The top cube G, marked as +, still hangs above F, which has fallen.
+        assert_eq!(canvases[5], ".+.\n.+.\n...\n...\n...\n###\n#.#\n###\n.#.\n");
+        let settled = ".#.\n.#.\n###\n#.#\n###\n.#.\n";
+        assert_eq!(canvases[6], "...\n".repeat(3) + settled);
+    }
+}
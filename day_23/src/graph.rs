@@ -1,23 +1,23 @@
 use std::collections::HashMap;
 
-use crate::{
-    coordinate::{Coordinate, Direction},
-    map::{Map, Step},
-};
+use graph::{search::Path, Interner, NodeId};
+use grid::{Direction, Position};
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
-struct Arc {
-    start: Coordinate,
-    end: Coordinate,
-}
+use crate::map::{Map, Step};
 
-pub(crate) struct Graph {
-    arcs: HashMap<Arc, usize>,
+/// The junctions of the map, connected by the lengths of the trails between them.
+pub struct Graph {
+    junctions: Interner<Position>,
+    trails: graph::Graph<usize>,
+    /// The tiles of every trail, without its start but with its end.
+    trail_tiles: HashMap<(NodeId, NodeId), Vec<Position>>,
 }
 
-impl From<&Map> for Graph {
-    fn from(map: &crate::map::Map) -> Self {
-        let mut arcs = HashMap::<Arc, usize>::new();
+impl Graph {
+    pub fn new(map: &Map, slippery: bool) -> Self {
+        let mut junctions = Interner::new();
+        let mut trails = graph::Graph::new();
+        let mut trail_tiles = HashMap::new();
 
         let mut start_fields = vec![Step {
             coordinate: map.start(),
@@ -25,39 +25,34 @@ impl From<&Map> for Graph {
         }];
 
         while let Some(mut step) = start_fields.pop() {
-            let start = step.coordinate;
-            step.coordinate = step.coordinate.next(&step.direction).unwrap();
-            let mut length = 1;
+            let start = junctions.intern(&step.coordinate);
+            step.coordinate = step.coordinate.step(step.direction).unwrap();
+            let mut tiles = vec![step.coordinate];
 
             loop {
-                let next_steps = map.next_steps(&step);
+                let next_steps = map.next_steps(&step, slippery);
                 match next_steps.len() {
                     0 => {
                         if step.coordinate == map.goal() {
-                            arcs.insert(
-                                Arc {
-                                    start,
-                                    end: step.coordinate,
-                                },
-                                length,
-                            );
+                            let end = junctions.intern(&step.coordinate);
+                            trails.add_edge(start, end, tiles.len());
+                            trail_tiles.insert((start, end), tiles);
                         }
                         break;
                     }
                     1 => {
                         step = next_steps[0];
-                        length += 1
+                        tiles.push(step.coordinate);
                     }
                     2 | 3 => {
-                        if let Some(old_arc) = arcs.insert(
-                            Arc {
-                                start,
-                                end: step.coordinate,
-                            },
-                            length,
-                        ) {
-                            assert!(old_arc == length);
+                        let end = junctions.intern(&step.coordinate);
+                        if let Some(old_trail) =
+                            trails.edges(start).iter().find(|trail| trail.to == end)
+                        {
+                            assert!(old_trail.weight == tiles.len());
                         } else {
+                            trails.add_edge(start, end, tiles.len());
+                            trail_tiles.insert((start, end), tiles);
                             for next_step in next_steps {
                                 start_fields.push(Step {
                                     coordinate: step.coordinate,
@@ -73,54 +68,26 @@ impl From<&Map> for Graph {
             }
         }
 
-        Self { arcs }
+        Self {
+            junctions,
+            trails,
+            trail_tiles,
+        }
     }
-}
 
-#[derive(Default, Clone)]
-struct Path {
-    length: usize,
-    visited: Vec<Coordinate>,
-}
-
-impl Graph {
-    pub(crate) fn longest_path(&self, start: &Coordinate, end: &Coordinate) -> Option<usize> {
-        let mut open_arcs = vec![(self.arcs_from(start)[0], Path::default())];
-        let mut longest_path = Path::default();
+    /// The longest hike from `start` to `end`, with all its tiles.
+    pub fn longest_path(&self, start: &Position, end: &Position) -> Option<Path<Position, usize>> {
+        let start_id = self.junctions.get(start)?;
+        let end_id = self.junctions.get(end)?;
+        let path = self.trails.longest_path(start_id, end_id)?;
 
-        while let Some((next_arc, current_path)) = open_arcs.pop() {
-            if current_path.visited.contains(&next_arc.start) {
-                continue;
-            }
-
-            let arc_length = self.arcs.get(&next_arc).unwrap();
-            let mut visited = current_path.visited.to_vec();
-            visited.push(next_arc.start);
-
-            let new_path = Path {
-                length: arc_length + current_path.length,
-                visited,
-            };
-
-            if next_arc.end == *end {
-                if new_path.length > longest_path.length {
-                    longest_path = new_path;
-                }
-            } else {
-                self.arcs_from(&next_arc.end).iter().for_each(|next_arc| {
-                    open_arcs.push((*next_arc, new_path.clone()));
-                });
-            }
+        let mut states = vec![*start];
+        for junctions in path.states.windows(2) {
+            states.extend(&self.trail_tiles[&(junctions[0], junctions[1])]);
         }
-
-        Some(longest_path.length)
-    }
-
-    fn arcs_from(&self, start: &Coordinate) -> Vec<Arc> {
-        self.arcs
-            .keys()
-            .filter(|arc| arc.start == *start)
-            .copied()
-            .collect()
+        Some(Path {
+            cost: path.cost,
+            states,
+        })
     }
 }
@@ -0,0 +1,84 @@
+pub mod graph;
+pub mod map;
+
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use graph::Graph;
+use grid::render::{Canvas, Colour};
+use map::Map;
+use std::str::FromStr;
+
+fn longest_hike(map: &Map, slippery: bool) -> usize {
+    let graph = Graph::new(map, slippery);
+    graph.longest_path(&map.start(), &map.goal()).unwrap().cost
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::from_str(input)
+}
+
+pub fn part1(map: &Map) -> usize {
+    longest_hike(map, true)
+}
+
+pub fn part2(map: &Map) -> usize {
+    longest_hike(map, false)
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Map;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(23, "default", Day23);
+}
+
+/// Draws the map with the longest hike of the part highlighted.
+pub fn render(map: &Map, part: u8) -> Canvas {
+    let graph = Graph::new(map, part == 1);
+    let hike = graph.longest_path(&map.start(), &map.goal()).unwrap();
+    map.canvas().overlay(hike.states, Colour::Red, Some('O'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&map), 94);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&map), 154);
+    }
+
+    #[test]
+    fn rendered_hike() {
+        let map = parse(include_str!("../data/demo_input.txt")).unwrap();
+        let tiles = render(&map, 1).to_string().matches('O').count();
+        assert_eq!(tiles, part1(&map) + 1);
+    }
+}
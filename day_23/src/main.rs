@@ -1,17 +1,13 @@
-mod coordinate;
-mod graph;
-mod map;
-
-use graph::Graph;
-use map::Map;
-use std::str::FromStr;
-
 fn main() {
-    let input = include_str!("../data/input.txt");
-    let map = Map::from_str(input).unwrap();
+    let input = common::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    let map = common::error::parse_or_exit(&input, day_23::parse);
 
-    let graph = Graph::from(&map);
-    let longest_path = graph.longest_path(&map.start(), &map.goal()).unwrap();
+    let longest_path = day_23::part1(&map);
+    println!("The longest path has {} steps.", longest_path);
 
-    println!("The longest path has {} steps.", longest_path)
+    let longest_path = day_23::part2(&map);
+    println!(
+        "Ignoring the slopes, the longest path has {} steps.",
+        longest_path
+    )
 }
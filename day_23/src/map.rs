@@ -1,4 +1,5 @@
-use crate::coordinate::*;
+use common::error::ParseError;
+use grid::{render::Canvas, Direction, Grid, Position};
 use std::str::FromStr;
 
 #[derive(PartialEq, Clone, Copy)]
@@ -8,126 +9,104 @@ enum Tile {
     Slope(Direction),
 }
 
-struct PositionNeighbors {
-    field: Coordinate,
-    step: u8,
-    direction: Direction,
-}
-
-impl Iterator for PositionNeighbors {
-    type Item = Step;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        while self.step < 4 {
-            self.step += 1;
-
-            let next_direction = match self.step {
-                1 if self.direction != Direction::Up => Direction::Down,
-                2 if self.direction != Direction::Down => Direction::Up,
-                3 if self.direction != Direction::Right => Direction::Left,
-                4 if self.direction != Direction::Left => Direction::Right,
-                _ => continue,
-            };
-            if let Some(coordinate) = self.field.next(&next_direction) {
-                return Some(Step {
-                    coordinate,
-                    direction: next_direction,
-                });
-            }
-        }
-        None
-    }
-}
-pub(crate) struct Map {
-    height: usize,
-    width: usize,
-    values: Vec<Tile>,
+pub struct Map {
+    tiles: Grid<Tile>,
 }
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let tiles = input
-            .lines()
-            .flat_map(|line| {
-                line.chars().map(|c| match c {
-                    '#' => Tile::Forest,
-                    '.' => Tile::Path,
-                    '<' => Tile::Slope(Direction::Left),
-                    '>' => Tile::Slope(Direction::Right),
-                    'v' => Tile::Slope(Direction::Down),
-                    '^' => Tile::Slope(Direction::Up),
-                    _ => unreachable!(),
-                })
-            })
-            .collect::<Vec<_>>();
-        let width = input.chars().position(|c| c == '\n').unwrap();
-        let height = tiles.len() / width;
-        Ok(Map {
-            width,
-            height,
-            values: tiles,
-        })
+        let tiles = Grid::parse(
+            input,
+            |c| match c {
+                '#' => Some(Tile::Forest),
+                '.' => Some(Tile::Path),
+                '<' => Some(Tile::Slope(Direction::Left)),
+                '>' => Some(Tile::Slope(Direction::Right)),
+                'v' => Some(Tile::Slope(Direction::Down)),
+                '^' => Some(Tile::Slope(Direction::Up)),
+                _ => None,
+            },
+            "'#', '.' or a slope",
+        )?;
+        Ok(Map { tiles })
     }
 }
 
 impl Map {
-    pub(crate) fn start(&self) -> Coordinate {
+    pub(crate) fn canvas(&self) -> Canvas {
+        Canvas::new(&self.tiles, |tile| match tile {
+            Tile::Path => '.',
+            Tile::Forest => '#',
+            Tile::Slope(Direction::Left) => '<',
+            Tile::Slope(Direction::Right) => '>',
+            Tile::Slope(Direction::Down) => 'v',
+            Tile::Slope(Direction::Up) => '^',
+        })
+    }
+
+    pub fn start(&self) -> Position {
         let x = self
-            .values
+            .tiles
+            .row(0)
             .iter()
             .position(|tile| tile == &Tile::Path)
             .unwrap();
-        Coordinate { x, y: 0 }
+        Position { x, y: 0 }
     }
 
-    pub(crate) fn goal(&self) -> Coordinate {
+    pub fn goal(&self) -> Position {
+        let y = self.tiles.height() - 1;
         let x = self
-            .values
+            .tiles
+            .row(y)
             .iter()
             .rposition(|tile| tile == &Tile::Path)
             .unwrap();
-        Coordinate {
-            x: self.width - (self.values.len() - x),
-            y: self.height - 1,
-        }
+        Position { x, y }
     }
-    pub(crate) fn next_steps(&self, step: &Step) -> Vec<Step> {
-        // if let Tile::Slope(direction) = self.value(&step.coordinate).unwrap() {
-        //     if *direction != step.direction {
-        //         return vec![];
-        //     }
-        //     match step.coordinate.next(direction) {
-        //         Some(coordinate) => vec![Step {
-        //             direction: *direction,
-        //             coordinate,
-        //         }],
-        //         None => vec![],
-        //     };
-        // }
 
-        PositionNeighbors {
-            field: step.coordinate,
-            step: 0,
-            direction: step.direction,
+    pub(crate) fn next_steps(&self, step: &Step, slippery: bool) -> Vec<Step> {
+        if slippery {
+            if let Some(Tile::Slope(direction)) = self.value(&step.coordinate) {
+                if *direction != step.direction {
+                    return vec![];
+                }
+                return match step.coordinate.step(*direction) {
+                    Some(coordinate) => vec![Step {
+                        direction: *direction,
+                        coordinate,
+                    }],
+                    None => vec![],
+                };
+            }
         }
-        .into_iter()
-        .filter(|step| {
-            self.value(&step.coordinate) != Some(&Tile::Forest)
-                && self.value(&step.coordinate).is_some()
-        })
-        .collect::<Vec<_>>()
+
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| *direction != step.direction.reverse())
+            .filter_map(|direction| {
+                let coordinate = step.coordinate.step(direction)?;
+                Some(Step {
+                    coordinate,
+                    direction,
+                })
+            })
+            .filter(|step| {
+                self.value(&step.coordinate) != Some(&Tile::Forest)
+                    && self.value(&step.coordinate).is_some()
+            })
+            .collect::<Vec<_>>()
     }
 
-    fn value(&self, coordinate: &Coordinate) -> Option<&Tile> {
-        let index = coordinate.y * self.width + coordinate.x;
-        self.values.get(index)
+    fn value(&self, coordinate: &Position) -> Option<&Tile> {
+        self.tiles.get(*coordinate)
     }
 }
 
 #[derive(Clone, Copy)]
 pub(crate) struct Step {
-    pub(crate) coordinate: Coordinate,
+    pub(crate) coordinate: Position,
     pub(crate) direction: Direction,
 }
@@ -0,0 +1,144 @@
+//! Random hailstones with a known rock throw for stress testing.
+
+use std::ops::{Range, RangeInclusive};
+
+use common::random::{Random, SizeError};
+use math_vector::Vector;
+
+/// `size` hailstones, at least six and at most 300, which a rock thrown from a known position
+/// with a known velocity hits at distinct times. Like in the real input, the positions lie
+/// around the test area of part 1, and for each axis there are two hailstones with the same
+/// velocity along it.
+pub fn generate(random: &mut Random, size: usize) -> Result<String, SizeError> {
+    let (_, hailstones) = hailstones(random, SizeError::check(size, SIZES)?);
+    let input = hailstones
+        .iter()
+        .map(|(position, velocity)| {
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                position.x, position.y, position.z, velocity.x, velocity.y, velocity.z
+            )
+        })
+        .collect();
+    Ok(input)
+}
+
+/// The supported numbers of hailstones.
+const SIZES: RangeInclusive<usize> = 6..=300;
+
+type Stone = (Vector<i64>, Vector<i64>);
+
+/// The rock and the hailstones, each as position and velocity.
+///
+/// Part 2 derives the velocity of the rock from the divisors of the distance between the first
+/// two hailstones with the same velocity along an axis. So these pairs are the only ones, and
+/// the rock hits them a prime number of nanoseconds apart, which keeps the divisors few.
+fn hailstones(random: &mut Random, count: usize) -> (Stone, Vec<Stone>) {
+    let position = [(); 3].map(|_| random.range(250_000_000_000_000..350_000_000_000_000));
+    let velocity = [(); 3].map(|_| non_zero(random, -100..100));
+
+    // Distinct along every axis, different from the rock, and never standing still along one.
+    let mut velocities = vec![[0; 3]; count];
+    for axis in [0, 1, 2] {
+        let mut taken = vec![];
+        while taken.len() < count {
+            let hailstone = velocity[axis] + non_zero(random, -200..200);
+            if hailstone != 0 && !taken.contains(&hailstone) {
+                taken.push(hailstone);
+            }
+        }
+        velocities
+            .iter_mut()
+            .zip(taken)
+            .for_each(|(hailstone, taken)| hailstone[axis] = taken);
+    }
+
+    let mut times = vec![];
+    while times.len() < count {
+        let time = random.range(100_000_000_000..1_000_000_000_000);
+        if !times.contains(&time) {
+            times.push(time);
+        }
+    }
+    let mut order = (0..count).collect::<Vec<_>>();
+    random.shuffle(&mut order);
+    for (axis, pair) in order.chunks(2).take(3).enumerate() {
+        let (from, to) = (pair[0], pair[1]);
+        velocities[to][axis] = velocities[from][axis];
+        times[to] = loop {
+            let time = times[from] + prime(random, 1_000_000..100_000_000_000);
+            if !times.contains(&time) {
+                break time;
+            }
+        };
+    }
+
+    let hailstones = velocities
+        .into_iter()
+        .zip(times)
+        .map(|(hailstone, time)| {
+            let hit = [0, 1, 2].map(|axis| position[axis] + velocity[axis] * time);
+            let start = [0, 1, 2].map(|axis| hit[axis] - hailstone[axis] * time);
+            (vector(start), vector(hailstone))
+        })
+        .collect();
+    ((vector(position), vector(velocity)), hailstones)
+}
+
+fn non_zero(random: &mut Random, range: Range<i64>) -> i64 {
+    loop {
+        let value = random.range(range.clone());
+        if value != 0 {
+            return value;
+        }
+    }
+}
+
+/// A random prime in `range`, whose end is at most 10^12.
+fn prime(random: &mut Random, range: Range<i64>) -> i64 {
+    loop {
+        let candidate = random.range(range.clone());
+        let mut divisors = (2..).take_while(|divisor| divisor * divisor <= candidate);
+        if candidate > 1 && divisors.all(|divisor| candidate % divisor != 0) {
+            return candidate;
+        }
+    }
+}
+
+fn vector([x, y, z]: [i64; 3]) -> Vector<i64> {
+    Vector::new(x, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part2};
+
+    #[test]
+    fn generated_hailstones() {
+        for seed in 0..5 {
+            let input = generate(&mut Random::new(seed), 20).unwrap();
+            assert_eq!(generate(&mut Random::new(seed), 20), Ok(input.clone()));
+
+            let hailstones = parse(&input).unwrap();
+            assert_eq!(hailstones.len(), 20);
+            let ((rock, _), _) = self::hailstones(&mut Random::new(seed), 20);
+            assert_eq!(part2(&hailstones), rock.x + rock.y + rock.z);
+        }
+    }
+
+    #[test]
+    fn smallest_size() {
+        for size in [0, 5, 301] {
+            let error = generate(&mut Random::new(0), size).unwrap_err();
+            assert_eq!(error.supported, SIZES);
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Random::new(seed), 6).unwrap();
+            let hailstones = parse(&input).unwrap();
+            assert_eq!(hailstones.len(), 6);
+            let ((rock, _), _) = self::hailstones(&mut Random::new(seed), 6);
+            assert_eq!(part2(&hailstones), rock.x + rock.y + rock.z);
+        }
+    }
+}
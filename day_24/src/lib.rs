@@ -0,0 +1,278 @@
+use common::{
+    answer::Answer,
+    error::{self, ParseError},
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use core::f64;
+use is_close::is_close;
+use math_vector::Vector;
+use regex::Regex;
+use std::{ops::RangeInclusive, str::FromStr};
+
+pub mod generator;
+
+pub struct Hailstone<T> {
+    pub position: Vector<T>,
+    pub velocity: Vector<T>,
+}
+
+impl Hailstone<i64> {
+    fn as_f64s(&self) -> Hailstone<f64> {
+        Hailstone {
+            position: self.position.as_f64s(),
+            velocity: self.velocity.as_f64s(),
+        }
+    }
+}
+
+impl FromStr for Hailstone<i64> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rx = Regex::new(r"(-?\d+)").unwrap();
+        let mut matches = rx.find_iter(input);
+        let mut next_number = || match matches.next() {
+            Some(number) => error::number(input, number.as_str()),
+            None => Err(ParseError::at_end(input, "a number")),
+        };
+
+        let x = next_number()?;
+        let y = next_number()?;
+        let z = next_number()?;
+        let vx = next_number()?;
+        let vy = next_number()?;
+        let vz = next_number()?;
+
+        Ok(Hailstone {
+            position: Vector::new(x, y, z),
+            velocity: Vector::new(vx, vy, vz),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Hailstone<i64>>, ParseError> {
+    error::lines(input)
+}
+
+fn trajectories_intersect(
+    h1: &Hailstone<f64>,
+    h2: &Hailstone<f64>,
+    range: &RangeInclusive<f64>,
+) -> Option<Vector<f64>> {
+    // y = a*x + b
+    let a1 = h1.velocity.y / h1.velocity.x;
+    let b1 = h1.position.y - a1 * h1.position.x;
+    let a2 = h2.velocity.y / h2.velocity.x;
+    let b2 = h2.position.y - a2 * h2.position.x;
+
+    if is_close!(a1, a2) {
+        // Parallel (a1==a2), maybe identical (if b1==b2)
+        return None;
+    }
+    let x = (b2 - b1) / (a1 - a2);
+    let y = a1 * x + b1;
+    if !range.contains(&x) || !range.contains(&y) {
+        // Out of area of interest
+        return None;
+    }
+    let t1 = (x - h1.position.x) / h1.velocity.x;
+    let t2 = (x - h2.position.x) / h2.velocity.x;
+    if t1 < 0.0 || t2 < 0.0 {
+        // Crossed in the past
+        return None;
+    }
+    Some(h1.position + h1.velocity * t1)
+}
+
+pub fn count_intersections(hailstones: &[Hailstone<i64>], range: &RangeInclusive<f64>) -> usize {
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(index, h1)| hailstones[index + 1..].iter().map(move |h2| (h1, h2)))
+        .filter(|(h1, h2)| trajectories_intersect(&h1.as_f64s(), &h2.as_f64s(), range).is_some())
+        .count()
+}
+
+fn intersect(h1: &Hailstone<f64>, h2: &Hailstone<f64>) -> bool {
+    // r1 + t*v1 == r2 + t*v2, for any t?
+    // r2 - r1 == t*(v1 - v2) => (r2 - r1) must be parallel to (v1 - v2)
+    let a = h2.position - h1.position;
+    let b = h1.velocity - h2.velocity;
+
+    let t = a.x / b.x;
+    is_close!(t * b.y, a.y) && is_close!(t * b.z, a.z)
+}
+
+fn find<F>(hailstones: &[Hailstone<i64>], f: F) -> Vec<Vector<i64>>
+where
+    F: Fn(Vector<i64>) -> Vector<i64>,
+{
+    use divisors_fixed::Divisors;
+
+    let (a, b) = hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(index, h1)| hailstones[index + 1..].iter().map(move |h2| (h1, h2)))
+        .find(|(a, b)| f(a.velocity) == f(b.velocity))
+        .unwrap();
+
+    let p1 = f(a.position).as_f64s().length() as i64;
+    let p2 = f(b.position).as_f64s().length() as i64;
+    let divisors = (p2 - p1).unsigned_abs().divisors();
+    let unit = Vector::<i64>::from([
+        f(a.velocity).x.checked_div(f(a.velocity).x).unwrap_or(0),
+        f(a.velocity).y.checked_div(f(a.velocity).y).unwrap_or(0),
+        f(a.velocity).z.checked_div(f(a.velocity).z).unwrap_or(0),
+    ]);
+
+    let v = divisors
+        .iter()
+        .flat_map(|divisor| [*divisor as i64, -(*divisor as i64)])
+        .map(|divisor| unit * divisor + f(a.velocity))
+        .collect::<Vec<_>>();
+    v
+}
+
+fn find_start_velocity(hailstones: &[Hailstone<i64>]) -> Option<Vector<i64>> {
+    let v_x = find(hailstones, Vector::<i64>::abscissa);
+    let v_y = find(hailstones, Vector::<i64>::ordinate);
+    let v_z = find(hailstones, Vector::<i64>::applicate);
+    let possible_velocities = v_x
+        .iter()
+        .flat_map(|x| v_y.iter().map(move |y| x + y))
+        .flat_map(|x| v_z.iter().map(move |z| x + *z))
+        .collect::<Vec<_>>();
+
+    for v in possible_velocities {
+        let v_norm = v.as_f64s().normalize();
+        let e_1 = Vector::new(v_norm.y, -v_norm.x, 0.0).normalize();
+        assert!(is_close::is_close!(
+            Vector::dot(v_norm, e_1),
+            0.0,
+            abs_tol = 1e-10
+        ));
+        let e_2 = Vector::cross(v_norm, e_1).normalize();
+
+        let mapped_stones = hailstones
+            .iter()
+            .map(|h| {
+                let mapped_p = e_1 * Vector::dot(e_1, h.position.as_f64s())
+                    + e_2 * Vector::dot(e_2, h.position.as_f64s());
+                let mapped_v = e_1 * Vector::dot(e_1, h.velocity.as_f64s())
+                    + e_2 * Vector::dot(e_2, h.velocity.as_f64s());
+
+                Hailstone {
+                    position: mapped_p,
+                    velocity: mapped_v,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut pairs = mapped_stones
+            .iter()
+            .enumerate()
+            .flat_map(|(index, h1)| mapped_stones[index + 1..].iter().map(move |h2| (h1, h2)));
+
+        let (h1, h2) = pairs.next().unwrap();
+        if let Some(intersection_mapped) = trajectories_intersect(h1, h2, &(f64::MIN..=f64::MAX)) {
+            if pairs
+                .filter_map(|(h1, h2)| trajectories_intersect(h1, h2, &(f64::MIN..=f64::MAX)))
+                .all(|x| {
+                    is_close!(x.x, intersection_mapped.x)
+                        && is_close!(x.y, intersection_mapped.y)
+                        && is_close!(x.z, intersection_mapped.z)
+                })
+            {
+                return Some(v);
+            }
+        }
+    }
+
+    None
+}
+
+fn find_start_position(
+    hailstones: &[Hailstone<i64>],
+    velocity: &Vector<i64>,
+) -> Option<Vector<i64>> {
+    let h1 = &hailstones[0];
+    let h2 = &hailstones[1];
+    let v1x = h1.velocity.x;
+    let v1y = h1.velocity.y;
+    let r1x = h1.position.x;
+    let r1y = h1.position.y;
+
+    let v2x = h2.velocity.x;
+    let v2y = h2.velocity.y;
+    let r2x = h2.position.x;
+    let r2y = h2.position.y;
+    let vx = velocity.x;
+    let vy = velocity.y;
+
+    let t2 = ((v1x - vx) * (r2y - r1y) - (v1y - vy) * (r2x - r1x))
+        / ((v2x - vx) * (v1y - vy) - (v2y - vy) * (v1x - vx));
+    let p = h2.position + h2.velocity * t2;
+    let r = p - velocity * t2;
+    Some(r)
+}
+
+fn is_valid(stone: &Hailstone<i64>, hailstones: &[Hailstone<i64>]) -> bool {
+    hailstones
+        .iter()
+        .all(|h| intersect(&h.as_f64s(), &stone.as_f64s()))
+}
+
+pub fn part1(hailstones: &[Hailstone<i64>]) -> usize {
+    // count_intersections(hailstones, &(7.0..=27.0))
+    count_intersections(hailstones, &(200000000000000.0..=400000000000000.0))
+}
+
+pub fn part2(hailstones: &[Hailstone<i64>]) -> i64 {
+    let velocity = find_start_velocity(hailstones).unwrap();
+    let position = find_start_position(hailstones, &velocity).unwrap();
+    let stone = Hailstone { velocity, position };
+    assert!(is_valid(&stone, hailstones));
+
+    position.x + position.y + position.z
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Vec<Hailstone<i64>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        Some(Ok(part2(parsed).into()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(24, "default", Day24);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let hailstones = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(count_intersections(&hailstones, &(7.0..=27.0)), 2);
+    }
+
+    #[test]
+    fn demo_part2() {
+        let hailstones = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part2(&hailstones), 47);
+    }
+}
@@ -0,0 +1,121 @@
+//! The older solution: contracts random wires until only two groups of components are left,
+//! and tries again until those are joined by three wires.
+
+use common::{
+    answer::Answer, error::ParseError, random::Random, solution::Solution, total::Overflow,
+};
+use graph::{Graph, NodeId};
+
+/// Gives up after this many contractions that do not end with three wires.
+const ATTEMPTS: usize = 10_000;
+
+/// The contraction solution for the registry of the runner.
+pub struct Contraction;
+
+impl Solution for Contraction {
+    type Parsed<'a> = Graph;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        crate::parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, _: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        None
+    }
+}
+
+pub fn part1(graph: &Graph) -> usize {
+    let wires = graph
+        .nodes()
+        .flat_map(|from| {
+            graph
+                .neighbours(from)
+                .filter(move |to| from < *to)
+                .map(move |to| (from, to))
+        })
+        .collect::<Vec<_>>();
+    let mut random = Random::new(25);
+
+    for _ in 0..ATTEMPTS {
+        let mut groups = Groups::new(graph.node_count());
+        let mut order = wires.clone();
+        random.shuffle(&mut order);
+        for (a, b) in order {
+            if groups.count == 2 {
+                break;
+            }
+            groups.join(a, b);
+        }
+
+        let cut = wires
+            .iter()
+            .filter(|(a, b)| groups.find(*a) != groups.find(*b))
+            .count();
+        if groups.count == 2 && cut == 3 {
+            let first = graph.nodes().next().expect("There are wires.");
+            let size = groups.size(first);
+            return size * (graph.node_count() - size);
+        }
+    }
+    panic!("The groups are not joined by three wires.");
+}
+
+/// The contracted groups of components as a union-find forest.
+struct Groups {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl Groups {
+    fn new(count: usize) -> Self {
+        Groups {
+            parents: (0..count).collect(),
+            sizes: vec![1; count],
+            count,
+        }
+    }
+
+    fn find(&mut self, node: NodeId) -> usize {
+        let mut node = node.index();
+        while self.parents[node] != node {
+            self.parents[node] = self.parents[self.parents[node]];
+            node = self.parents[node];
+        }
+        node
+    }
+
+    fn join(&mut self, a: NodeId, b: NodeId) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            let (small, large) = if self.sizes[a] < self.sizes[b] {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            self.parents[small] = large;
+            self.sizes[large] += self.sizes[small];
+            self.count -= 1;
+        }
+    }
+
+    fn size(&mut self, node: NodeId) -> usize {
+        let root = self.find(node);
+        self.sizes[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let graph = crate::parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&graph), 54);
+    }
+}
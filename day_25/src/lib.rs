@@ -0,0 +1,74 @@
+use common::{
+    answer::Answer,
+    error::ParseError,
+    solution::{Registry, Solution},
+    total::Overflow,
+};
+use graph::{Graph, Interner};
+
+pub mod contraction;
+pub mod spectral;
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut names = Interner::new();
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (component, others) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(input, &line[line.len()..], "\": \""))?;
+        let component = names.intern(component);
+        for other in others.split_ascii_whitespace() {
+            graph.add_undirected_edge(component, names.intern(other), ());
+        }
+    }
+    Ok(graph)
+}
+
+pub fn part1(graph: &Graph) -> usize {
+    let cut = graph
+        .min_cut()
+        .expect("There are less than two components.");
+    assert_eq!(
+        cut.edges.len(),
+        3,
+        "The groups are not joined by three wires."
+    );
+
+    cut.side.len() * (graph.node_count() - cut.side.len())
+}
+
+/// The solution of the day for the registry of the runner.
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = Graph;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, _: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        None
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(25, "min-cut", Day25);
+    registry.register(25, "spectral", spectral::Spectral);
+    registry.register(25, "contraction", contraction::Contraction);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let graph = parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&graph), 54);
+    }
+}
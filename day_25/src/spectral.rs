@@ -0,0 +1,129 @@
+//! The fancy solution: splits the components by the signs of the Fiedler vector of the
+//! Laplacian matrix of the wires, which separates two well connected groups joined by few wires.
+//! Found idea here: https://www.reddit.com/r/adventofcode/comments/18qbsxs/comment/kgxsxbz
+
+use common::{answer::Answer, error::ParseError, solution::Solution, total::Overflow};
+use graph::{Graph, NodeId};
+use nalgebra::{DMatrix, SymmetricEigen};
+
+/// The spectral solution for the registry of the runner.
+pub struct Spectral;
+
+impl Solution for Spectral {
+    type Parsed<'a> = Graph;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        crate::parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, Overflow> {
+        Ok(part1(parsed).into())
+    }
+
+    fn part2(&self, _: &Self::Parsed<'_>) -> Option<Result<Answer, Overflow>> {
+        None
+    }
+}
+
+/// The degrees of the components on the diagonal, and -1 for every wire between two of them.
+pub fn laplacian(graph: &Graph) -> DMatrix<f64> {
+    let nodes = graph.node_count();
+    let mut matrix = DMatrix::zeros(nodes, nodes);
+    for from in graph.nodes() {
+        for to in graph.neighbours(from) {
+            matrix[(from.index(), from.index())] += 1.0;
+            matrix[(from.index(), to.index())] -= 1.0;
+        }
+    }
+    matrix
+}
+
+/// The components with a negative entry in the Fiedler vector, the eigenvector of the second
+/// smallest eigenvalue.
+pub fn partition(graph: &Graph) -> Vec<NodeId> {
+    let eigen = SymmetricEigen::new(laplacian(graph));
+
+    let mut order = (0..eigen.eigenvalues.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| eigen.eigenvalues[*a].total_cmp(&eigen.eigenvalues[*b]));
+    let index = order[1];
+
+    let eigenvector = &eigen.eigenvectors.column(index);
+    graph
+        .nodes()
+        .filter(|node| eigenvector[node.index()] < 0.0)
+        .collect()
+}
+
+pub fn part1(graph: &Graph) -> usize {
+    let side = partition(graph).len();
+    side * (graph.node_count() - side)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn demo_part1() {
+        let graph = crate::parse(include_str!("../data/demo_input.txt")).unwrap();
+        assert_eq!(part1(&graph), 54);
+    }
+
+    /// The number of wires between the components in `side` and the others.
+    fn crossing_wires(wires: &[(usize, usize)], side: impl Fn(usize) -> bool) -> usize {
+        wires.iter().filter(|(a, b)| side(*a) != side(*b)).count()
+    }
+
+    /// Two groups of five to eight components, with most wires within a group present, joined
+    /// by three random wires.
+    fn joined_groups() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+        (5usize..=8, 5usize..=8).prop_flat_map(|(left, right)| {
+            let nodes = left + right;
+            let pairs = (0..nodes)
+                .flat_map(|a| (a + 1..nodes).map(move |b| (a, b)))
+                .filter(|(a, b)| (*a < left) == (*b < left))
+                .collect::<Vec<_>>();
+            let within = prop::collection::vec(prop::bool::weighted(0.9), pairs.len()).prop_map(
+                move |present| {
+                    pairs
+                        .iter()
+                        .zip(present)
+                        .filter_map(|(pair, present)| present.then_some(*pair))
+                        .collect::<Vec<_>>()
+                },
+            );
+            let between = prop::collection::vec((0..left, left..nodes), 3);
+            (Just(nodes), within, between).prop_map(|(nodes, mut wires, between)| {
+                wires.extend(between);
+                (nodes, wires)
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn partition_by_brute_force((nodes, wires) in joined_groups()) {
+            // Every split into two non-empty groups, where the bits choose which of the other
+            // components leave the group of the first one. Like in the puzzle, only one of them
+            // cuts no more than three wires.
+            let small_cuts = (1..1u32 << (nodes - 1))
+                .map(|leaving| {
+                    crossing_wires(&wires, |node| node == 0 || leaving & 1 << (node - 1) == 0)
+                })
+                .filter(|crossing| *crossing <= 3)
+                .collect::<Vec<_>>();
+            prop_assume!(small_cuts == [3]);
+
+            let mut graph = Graph::new();
+            let ids = (0..nodes).map(|_| graph.add_node()).collect::<Vec<_>>();
+            for (a, b) in &wires {
+                graph.add_undirected_edge(ids[*a], ids[*b], ());
+            }
+            let side = partition(&graph);
+            prop_assert!(!side.is_empty() && side.len() < nodes);
+            prop_assert_eq!(crossing_wires(&wires, |node| side.contains(&ids[node])), 3);
+        }
+    }
+}
@@ -0,0 +1,566 @@
+//! Graphs as adjacency lists over interned node IDs, with the searches the puzzles need.
+
+pub mod search;
+
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use search::{Cost, Path};
+
+/// Index of a node, handed out by an [`Interner`] or by [`Graph::add_node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Assigns consecutive node IDs to names, by default strings.
+#[derive(Debug, Clone)]
+pub struct Interner<K = String> {
+    keys: Vec<K>,
+    ids: HashMap<K, NodeId>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Interner {
+            keys: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> Interner<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ID of `key`, which gets the next free one if it is new.
+    pub fn intern<Q>(&mut self, key: &Q) -> NodeId
+    where
+        K: Borrow<Q>,
+        Q: ToOwned<Owned = K> + Eq + Hash + ?Sized,
+    {
+        if let Some(id) = self.get(key) {
+            return id;
+        }
+        let id = NodeId(self.keys.len());
+        self.keys.push(key.to_owned());
+        self.ids.insert(key.to_owned(), id);
+        id
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<NodeId>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+
+    /// The key interned as `id`. Panics for IDs of another interner.
+    pub fn resolve(&self, id: NodeId) -> &K {
+        &self.keys[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &K)> {
+        self.keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (NodeId(index), key))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge<W> {
+    pub to: NodeId,
+    pub weight: W,
+}
+
+/// A directed graph with weighted edges. Undirected graphs store every edge in both directions.
+///
+/// The edges of a node keep the order in which they were added.
+#[derive(Debug, Clone)]
+pub struct Graph<W = ()> {
+    adjacency: Vec<Vec<Edge<W>>>,
+}
+
+/// Edges whose removal splits a graph into two parts, and the nodes of one of the parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub edges: Vec<(NodeId, NodeId)>,
+    pub side: Vec<NodeId>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph {
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self) -> NodeId {
+        self.adjacency.push(Vec::new());
+        NodeId(self.adjacency.len() - 1)
+    }
+
+    /// Adds an edge from `from` to `to`, together with any nodes up to their IDs.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.reserve_node(from.max(to));
+        self.adjacency[from.0].push(Edge { to, weight });
+    }
+
+    /// Adds the edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    fn reserve_node(&mut self, node: NodeId) {
+        if self.adjacency.len() <= node.0 {
+            self.adjacency.resize_with(node.0 + 1, Vec::new);
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.adjacency.len()).map(NodeId)
+    }
+
+    /// The edges leaving `node`, which is empty for unknown nodes.
+    pub fn edges(&self, node: NodeId) -> &[Edge<W>] {
+        self.adjacency.get(node.0).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn neighbours(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(node).iter().map(|edge| edge.to)
+    }
+
+    /// The nodes with an edge to `node`.
+    pub fn predecessors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes()
+            .filter(move |from| self.neighbours(*from).any(|to| to == node))
+    }
+
+    /// The nodes reachable from `start` in breadth-first order.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.node_count().max(start.0 + 1)];
+        let mut queue = VecDeque::from([start]);
+        let mut order = Vec::new();
+        seen[start.0] = true;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for next in self.neighbours(node) {
+                if !seen[next.0] {
+                    seen[next.0] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    /// The nodes reachable from `start` in depth-first preorder.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.node_count().max(start.0 + 1)];
+        let mut stack = vec![start];
+        let mut order = Vec::new();
+        while let Some(node) = stack.pop() {
+            if seen[node.0] {
+                continue;
+            }
+            seen[node.0] = true;
+            order.push(node);
+            let edges = self.edges(node).iter().rev();
+            stack.extend(edges.map(|edge| edge.to).filter(|next| !seen[next.0]));
+        }
+        order
+    }
+
+    /// The number of edges on a shortest path from `start` to every node, if there is one.
+    pub fn distances(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.node_count().max(start.0 + 1)];
+        let mut queue = VecDeque::from([start]);
+        distances[start.0] = Some(0);
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[node.0].map(|distance| distance + 1);
+            for next in self.neighbours(node) {
+                if distances[next.0].is_none() {
+                    distances[next.0] = distance;
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// A path from `start` to `goal` with the fewest edges, including both ends.
+    pub fn shortest_path(&self, start: NodeId, goal: NodeId) -> Option<Vec<NodeId>> {
+        let mut previous = vec![None; self.node_count().max(start.0 + 1)];
+        let mut queue = VecDeque::from([start]);
+        previous[start.0] = Some(start);
+        while let Some(node) = queue.pop_front() {
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(before) = previous[path[path.len() - 1].0] {
+                    if before == path[path.len() - 1] {
+                        break;
+                    }
+                    path.push(before);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in self.neighbours(node) {
+                if previous[next.0].is_none() {
+                    previous[next.0] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// The largest total weight of a path from `start` to `goal` which visits no node twice.
+    ///
+    /// Tries all such paths, so it is only feasible for small graphs.
+    pub fn longest_path(&self, start: NodeId, goal: NodeId) -> Option<Path<NodeId, W>>
+    where
+        W: Cost,
+    {
+        let mut visited = vec![false; self.node_count().max(start.0 + 1)];
+        let mut longest = None;
+        self.extend_longest_path(
+            goal,
+            W::default(),
+            &mut vec![start],
+            &mut visited,
+            &mut longest,
+        );
+        longest
+    }
+
+    /// Tries all ways to continue `path` to `goal`, keeping the longest in `longest`.
+    fn extend_longest_path(
+        &self,
+        goal: NodeId,
+        length: W,
+        path: &mut Vec<NodeId>,
+        visited: &mut [bool],
+        longest: &mut Option<Path<NodeId, W>>,
+    ) where
+        W: Cost,
+    {
+        let node = path[path.len() - 1];
+        if node == goal {
+            if longest.as_ref().is_none_or(|longest| length > longest.cost) {
+                *longest = Some(Path {
+                    cost: length,
+                    states: path.clone(),
+                });
+            }
+            return;
+        }
+        visited[node.0] = true;
+        for edge in self.edges(node) {
+            if !visited[edge.to.0] {
+                path.push(edge.to);
+                self.extend_longest_path(goal, length + edge.weight, path, visited, longest);
+                path.pop();
+            }
+        }
+        visited[node.0] = false;
+    }
+
+    /// The connected components of an undirected graph, each in breadth-first order.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut seen = vec![false; self.node_count()];
+        let mut components = Vec::new();
+        for node in self.nodes() {
+            if !seen[node.0] {
+                let component = self.bfs(node);
+                component.iter().for_each(|node| seen[node.0] = true);
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    /// A cut of an undirected graph with the fewest edges, ignoring the weights. `None` for
+    /// graphs with less than two nodes.
+    ///
+    /// Computes unit-capacity maximum flows from the first node to every other one.
+    pub fn min_cut(&self) -> Option<Cut> {
+        if self.node_count() < 2 {
+            return None;
+        }
+        let twins = self.twin_edges();
+        let source = NodeId(0);
+        let mut best: Option<Vec<bool>> = None;
+        let mut best_size = usize::MAX;
+
+        for sink in self.nodes().skip(1) {
+            if let Some(side) = self.max_flow_side(source, sink, best_size, &twins) {
+                best_size = self.cut_edges(&side).count();
+                best = Some(side);
+                if best_size == 0 {
+                    break;
+                }
+            }
+        }
+
+        best.map(|side| Cut {
+            edges: self.cut_edges(&side).collect(),
+            side: self.nodes().filter(|node| side[node.0]).collect(),
+        })
+    }
+
+    /// For every edge the index of its reverse edge in the list of its target.
+    fn twin_edges(&self) -> Vec<Vec<usize>> {
+        let mut twins = self
+            .adjacency
+            .iter()
+            .map(|edges| vec![usize::MAX; edges.len()])
+            .collect::<Vec<_>>();
+        let mut unpaired = HashMap::<(NodeId, NodeId), Vec<usize>>::new();
+        for from in self.nodes() {
+            for (index, edge) in self.edges(from).iter().enumerate() {
+                match unpaired.get_mut(&(edge.to, from)).and_then(Vec::pop) {
+                    Some(twin) => {
+                        twins[from.0][index] = twin;
+                        twins[edge.to.0][twin] = index;
+                    }
+                    None => unpaired.entry((from, edge.to)).or_default().push(index),
+                }
+            }
+        }
+        assert!(
+            unpaired.values().all(Vec::is_empty),
+            "Minimum cuts need an undirected graph."
+        );
+        twins
+    }
+
+    /// Pushes unit flows from `source` to `sink`. If fewer than `limit` fit, returns which
+    /// nodes the residual graph still connects to `source`.
+    fn max_flow_side(
+        &self,
+        source: NodeId,
+        sink: NodeId,
+        limit: usize,
+        twins: &[Vec<usize>],
+    ) -> Option<Vec<bool>> {
+        let mut flow = self
+            .adjacency
+            .iter()
+            .map(|edges| vec![0i8; edges.len()])
+            .collect::<Vec<_>>();
+
+        for _ in 0..limit {
+            let mut previous: Vec<Option<(NodeId, usize)>> = vec![None; self.node_count()];
+            let mut reached = vec![false; self.node_count()];
+            let mut queue = VecDeque::from([source]);
+            reached[source.0] = true;
+            while let Some(node) = queue.pop_front() {
+                for (index, edge) in self.edges(node).iter().enumerate() {
+                    if flow[node.0][index] < 1 && !reached[edge.to.0] {
+                        reached[edge.to.0] = true;
+                        previous[edge.to.0] = Some((node, index));
+                        queue.push_back(edge.to);
+                    }
+                }
+            }
+
+            if !reached[sink.0] {
+                return Some(reached);
+            }
+            let mut node = sink;
+            while let Some((from, index)) = previous[node.0] {
+                flow[from.0][index] += 1;
+                flow[node.0][twins[from.0][index]] -= 1;
+                node = from;
+            }
+        }
+        None
+    }
+
+    fn cut_edges<'a>(&'a self, side: &'a [bool]) -> impl Iterator<Item = (NodeId, NodeId)> + 'a {
+        self.nodes()
+            .filter(|node| side[node.0])
+            .flat_map(move |from| {
+                self.neighbours(from)
+                    .filter(|to| !side[to.0])
+                    .map(move |to| (from, to))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn undirected(edges: &[(&str, &str)]) -> (Interner, Graph) {
+        let mut names = Interner::new();
+        let mut graph = Graph::new();
+        for (a, b) in edges {
+            let (a, b) = (names.intern(*a), names.intern(*b));
+            graph.add_undirected_edge(a, b, ());
+        }
+        (names, graph)
+    }
+
+    #[test]
+    fn interner() {
+        let mut names = Interner::new();
+        let a = names.intern("a");
+        let b = names.intern("b");
+        assert_eq!(names.intern("a"), a);
+        assert_eq!((a.index(), b.index()), (0, 1));
+        assert_eq!(names.get("b"), Some(b));
+        assert_eq!(names.get("c"), None);
+        assert_eq!(names.resolve(b), "b");
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn searches() {
+        let (names, graph) =
+            undirected(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("x", "y")]);
+        let id = |name| names.get(name).unwrap();
+        let resolve = |nodes: Vec<NodeId>| {
+            nodes
+                .into_iter()
+                .map(|node| names.resolve(node).as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(resolve(graph.bfs(id("a"))), ["a", "b", "c", "d"]);
+        assert_eq!(resolve(graph.dfs(id("a"))), ["a", "b", "d", "c"]);
+        assert_eq!(graph.distances(id("a"))[id("d").index()], Some(2));
+        assert_eq!(graph.distances(id("a"))[id("x").index()], None);
+        assert_eq!(
+            resolve(graph.shortest_path(id("d"), id("a")).unwrap()),
+            ["d", "b", "a"]
+        );
+        assert_eq!(graph.shortest_path(id("a"), id("y")), None);
+        assert_eq!(
+            graph.predecessors(id("d")).collect::<Vec<_>>(),
+            [id("b"), id("c")]
+        );
+
+        let components = graph.connected_components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(resolve(components[1].clone()), ["x", "y"]);
+    }
+
+    #[test]
+    fn longest_path() {
+        let mut graph = Graph::new();
+        let nodes = [(); 4].map(|_| graph.add_node());
+        graph.add_undirected_edge(nodes[0], nodes[1], 5);
+        graph.add_undirected_edge(nodes[1], nodes[2], 1);
+        graph.add_undirected_edge(nodes[0], nodes[2], 2);
+        graph.add_undirected_edge(nodes[2], nodes[3], 1);
+        let path = graph.longest_path(nodes[0], nodes[3]).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states, [nodes[0], nodes[1], nodes[2], nodes[3]]);
+        let path = graph.longest_path(nodes[3], nodes[3]).unwrap();
+        assert_eq!((path.cost, path.states), (0, vec![nodes[3]]));
+
+        graph.add_node();
+        assert_eq!(graph.longest_path(nodes[0], NodeId(4)), None);
+    }
+
+    #[test]
+    fn min_cut() {
+        // Two triangles, joined by a single edge.
+        let (names, graph) = undirected(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+        ]);
+        let cut = graph.min_cut().unwrap();
+        assert_eq!(cut.edges.len(), 1);
+        assert_eq!(cut.side.len(), 3);
+        let (c, d) = (names.get("c").unwrap(), names.get("d").unwrap());
+        assert!(cut.edges == [(c, d)] || cut.edges == [(d, c)]);
+
+        let (_, graph) = undirected(&[("a", "b"), ("x", "y")]);
+        assert_eq!(graph.min_cut().unwrap().edges, []);
+        assert_eq!(Graph::<()>::new().min_cut(), None);
+    }
+
+    /// The number of edges between the nodes in `side` and the others.
+    fn crossing_edges(edges: &[(usize, usize)], side: impl Fn(usize) -> bool) -> usize {
+        edges.iter().filter(|(a, b)| side(*a) != side(*b)).count()
+    }
+
+    /// Up to eight nodes and a few edges between distinct ones, possibly repeated.
+    fn small_graph() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+        (2usize..=8).prop_flat_map(|nodes| {
+            let edge = (0..nodes, 0..nodes).prop_filter("No loops.", |(a, b)| a != b);
+            (Just(nodes), prop::collection::vec(edge, 0..20))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn min_cut_by_brute_force((nodes, edges) in small_graph()) {
+            let mut graph = Graph::new();
+            (0..nodes).for_each(|_| {
+                graph.add_node();
+            });
+            for (a, b) in &edges {
+                graph.add_undirected_edge(NodeId(*a), NodeId(*b), ());
+            }
+
+            // Every split into two non-empty parts, where the bits choose which of the other
+            // nodes leave the part of the first node.
+            let smallest = (1..1u32 << (nodes - 1))
+                .map(|leaving| {
+                    crossing_edges(&edges, |node| node == 0 || leaving & 1 << (node - 1) == 0)
+                })
+                .min()
+                .unwrap();
+
+            let cut = graph.min_cut().unwrap();
+            prop_assert_eq!(cut.edges.len(), smallest);
+            prop_assert!(!cut.side.is_empty() && cut.side.len() < nodes);
+            let side = |node| cut.side.contains(&NodeId(node));
+            prop_assert_eq!(crossing_edges(&edges, side), smallest);
+            prop_assert!(cut.edges.iter().all(|(from, to)| side(from.0) && !side(to.0)));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Cheapest paths through implicit graphs, whose states and edges are produced on demand.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of an edge or a path. Costs must not be negative.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// A cheapest path and its cost. The states include the start and the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    previous: Option<usize>,
+}
+
+/// The states found so far, and the ones still to expand ordered by their estimated total cost.
+struct Frontier<S, C> {
+    nodes: Vec<Node<S, C>>,
+    indices: HashMap<S, usize>,
+    open: BinaryHeap<Reverse<(C, C, usize)>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Frontier<S, C> {
+    fn new(start: S, estimate: C) -> Self {
+        Frontier {
+            nodes: vec![Node {
+                state: start.clone(),
+                cost: C::default(),
+                previous: None,
+            }],
+            indices: HashMap::from([(start, 0)]),
+            open: BinaryHeap::from([Reverse((estimate, C::default(), 0))]),
+        }
+    }
+
+    /// The next state to expand, skipping the ones reached more cheaply since they were queued.
+    fn pop(&mut self) -> Option<usize> {
+        while let Some(Reverse((_, cost, index))) = self.open.pop() {
+            if cost == self.nodes[index].cost {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    /// Records that `state` can be reached from the node at `previous` at `cost`.
+    fn relax(&mut self, state: S, cost: C, previous: usize, heuristic: impl FnOnce(&S) -> C) {
+        let index = match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let node = &mut self.nodes[*entry.get()];
+                if cost >= node.cost {
+                    return;
+                }
+                node.cost = cost;
+                node.previous = Some(previous);
+                *entry.get()
+            }
+            Entry::Vacant(entry) => {
+                self.nodes.push(Node {
+                    state: entry.key().clone(),
+                    cost,
+                    previous: Some(previous),
+                });
+                *entry.insert(self.nodes.len() - 1)
+            }
+        };
+        let estimate = cost + heuristic(&self.nodes[index].state);
+        self.open.push(Reverse((estimate, cost, index)));
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut index = Some(goal);
+        while let Some(current) = index {
+            states.push(self.nodes[current].state.clone());
+            index = self.nodes[current].previous;
+        }
+        states.reverse();
+        Path {
+            cost: self.nodes[goal].cost,
+            states,
+        }
+    }
+}
+
+/// A cheapest path from `start` to a state for which `is_goal` holds.
+///
+/// `successors` lists the states reachable from a state, each with the cost of the step.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but expands first the states with the lowest cost plus `heuristic`. The
+/// heuristic must never overestimate the remaining cost, or the path may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let estimate = heuristic(&start);
+    let mut frontier = Frontier::new(start, estimate);
+
+    while let Some(index) = frontier.pop() {
+        let node = &frontier.nodes[index];
+        if is_goal(&node.state) {
+            return Some(frontier.path(index));
+        }
+        let cost = node.cost;
+        for (next, step) in successors(&node.state) {
+            frontier.relax(next, cost + step, index, &mut heuristic);
+        }
+    }
+    None
+}
+
+/// The lowest cost of every state reachable from `start` at a cost of at most `max_cost`.
+pub fn costs<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, max_cost: C) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut frontier = Frontier::new(start, C::default());
+
+    while let Some(index) = frontier.pop() {
+        let cost = frontier.nodes[index].cost;
+        for (next, step) in successors(&frontier.nodes[index].state) {
+            if cost + step <= max_cost {
+                frontier.relax(next, cost + step, index, |_| C::default());
+            }
+        }
+    }
+    frontier
+        .nodes
+        .into_iter()
+        .map(|node| (node.state, node.cost))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps along a line of numbers: one forward costs 3, two forward cost 5, one back costs 1.
+    fn successors(n: &i32) -> [(i32, u32); 3] {
+        [(n + 1, 3), (n + 2, 5), (n - 1, 1)]
+    }
+
+    #[test]
+    fn cheapest_paths() {
+        let path = dijkstra(0, successors, |n| *n == 4).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states, [0, 2, 4]);
+
+        let path = astar(0, successors, |n| (4 - n).max(0) as u32, |n| *n == 4).unwrap();
+        assert_eq!(path.cost, 10);
+
+        let start = dijkstra(5, successors, |_| true).unwrap();
+        assert_eq!((start.cost, start.states), (0, vec![5]));
+
+        let bounded = |n: &i32| {
+            successors(n)
+                .into_iter()
+                .filter(|(n, _)| (0..4).contains(n))
+        };
+        assert_eq!(dijkstra(0, bounded, |n| *n == 7), None);
+    }
+
+    #[test]
+    fn all_costs() {
+        let costs = costs(0, successors, 5);
+        assert_eq!(costs.len(), 8);
+        assert_eq!(costs[&2], 5);
+        assert_eq!(costs[&-5], 5);
+        assert_eq!(costs.get(&3), None);
+    }
+}
@@ -0,0 +1,261 @@
+//! Export of canvases and polygons as image files: binary PPM and SVG.
+
+use std::fmt::Write;
+
+use common::polygon::Polygon;
+
+use crate::{
+    render::{Canvas, Cell, Colour},
+    Position,
+};
+
+/// Red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// The RGB values for the colours of a canvas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// For uncoloured cells showing empty space: `'.'` or `' '`.
+    pub background: Rgb,
+    /// For all other uncoloured cells.
+    pub plain: Rgb,
+    pub colours: [Rgb; 8],
+}
+
+impl Palette {
+    /// Light colours on black.
+    pub fn dark() -> Self {
+        Palette {
+            background: [0, 0, 0],
+            plain: [96, 96, 96],
+            colours: [
+                [64, 64, 64],
+                [240, 80, 80],
+                [80, 220, 80],
+                [240, 220, 60],
+                [90, 140, 255],
+                [220, 100, 220],
+                [80, 220, 220],
+                [255, 255, 255],
+            ],
+        }
+    }
+
+    /// Dark colours on white.
+    pub fn light() -> Self {
+        Palette {
+            background: [255, 255, 255],
+            plain: [180, 180, 180],
+            colours: [
+                [0, 0, 0],
+                [200, 0, 0],
+                [0, 150, 0],
+                [200, 150, 0],
+                [0, 60, 220],
+                [160, 0, 160],
+                [0, 150, 150],
+                [120, 120, 120],
+            ],
+        }
+    }
+
+    pub fn colour(&self, colour: Colour) -> Rgb {
+        self.colours[colour as usize]
+    }
+
+    fn cell(&self, cell: &Cell) -> Rgb {
+        match (cell.colour, cell.symbol) {
+            (Some(colour), _) => self.colour(colour),
+            (None, '.' | ' ') => self.background,
+            (None, _) => self.plain,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// How images are drawn: every cell of a canvas becomes a square of `cell_size` pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Style {
+    pub cell_size: usize,
+    pub palette: Palette,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            cell_size: 4,
+            palette: Palette::default(),
+        }
+    }
+}
+
+/// Polygons are scaled down until they fit into this many pixels in both directions.
+const MAX_POLYGON_PIXELS: f64 = 2048.0;
+
+impl Style {
+    /// The canvas as binary PPM (P6) image.
+    pub fn ppm(&self, canvas: &Canvas) -> Vec<u8> {
+        let cells = canvas.cells();
+        let (width, height) = (
+            cells.width() * self.cell_size,
+            cells.height() * self.cell_size,
+        );
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        image.reserve(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let position = Position::new(x / self.cell_size, y / self.cell_size);
+                image.extend(self.palette.cell(&cells[position]));
+            }
+        }
+        image
+    }
+
+    /// The canvas as SVG image, with one square per cell.
+    pub fn svg(&self, canvas: &Canvas) -> String {
+        let cells = canvas.cells();
+        let size = self.cell_size;
+        let mut svg = header(cells.width() * size, cells.height() * size, None);
+        rect(
+            &mut svg,
+            0,
+            0,
+            cells.width() * size,
+            cells.height() * size,
+            self.palette.background,
+        );
+        for (position, cell) in cells.iter() {
+            let fill = self.palette.cell(cell);
+            if fill != self.palette.background {
+                rect(
+                    &mut svg,
+                    position.x * size,
+                    position.y * size,
+                    size,
+                    size,
+                    fill,
+                );
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The polygon as SVG image, filled with `colour`. A unit of the coordinates becomes
+    /// `cell_size` pixels, unless that exceeds 2048 pixels, in which case it is scaled down.
+    pub fn polygon_svg(&self, polygon: &Polygon, colour: Colour) -> String {
+        let vertices = polygon.vertices();
+        let min_x = vertices.iter().map(|vertex| vertex.x).min().unwrap_or(0);
+        let max_x = vertices.iter().map(|vertex| vertex.x).max().unwrap_or(0);
+        let min_y = vertices.iter().map(|vertex| vertex.y).min().unwrap_or(0);
+        let max_y = vertices.iter().map(|vertex| vertex.y).max().unwrap_or(0);
+        let (width, height) = ((max_x - min_x + 1) as f64, (max_y - min_y + 1) as f64);
+        let scale = (self.cell_size as f64).min(MAX_POLYGON_PIXELS / width.max(height));
+
+        let view_box = format!("{min_x} {min_y} {width} {height}");
+        let mut svg = header(
+            (width * scale).round() as usize,
+            (height * scale).round() as usize,
+            Some(&view_box),
+        );
+        let points = vertices
+            .iter()
+            .map(|vertex| format!("{},{}", vertex.x, vertex.y))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(
+            svg,
+            r#"<rect x="{min_x}" y="{min_y}" width="{width}" height="{height}" fill="{}"/>"#,
+            hex(self.palette.background)
+        )
+        .expect("Writing to a string cannot fail.");
+        writeln!(
+            svg,
+            r#"<polygon points="{points}" fill="{}" stroke="{}" stroke-width="{}"/>"#,
+            hex(self.palette.colour(colour)),
+            hex(self.palette.plain),
+            1.0 / scale
+        )
+        .expect("Writing to a string cannot fail.");
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn header(width: usize, height: usize, view_box: Option<&str>) -> String {
+    let view_box =
+        view_box.map_or_else(String::new, |view_box| format!(r#" viewBox="{view_box}""#));
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\"{view_box}>\n"
+    )
+}
+
+fn rect(svg: &mut String, x: usize, y: usize, width: usize, height: usize, fill: Rgb) {
+    writeln!(
+        svg,
+        r#"<rect x="{x}" y="{y}" width="{width}" height="{height}" fill="{}"/>"#,
+        hex(fill)
+    )
+    .expect("Writing to a string cannot fail.");
+}
+
+fn hex([red, green, blue]: Rgb) -> String {
+    format!("#{red:02x}{green:02x}{blue:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::geometry::Coordinate;
+
+    use super::*;
+    use crate::Grid;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::parse("#.\n..", Some, "").unwrap();
+        Canvas::new(&grid, |c| *c).overlay([Position::new(1, 1)], Colour::Red, None)
+    }
+
+    #[test]
+    fn ppm() {
+        let style = Style {
+            cell_size: 2,
+            palette: Palette::light(),
+        };
+        let image = style.ppm(&canvas());
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&image[..header.len()], header);
+
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        let pixel = |x: usize, y: usize| &pixels[(y * 4 + x) * 3..][..3];
+        assert_eq!(pixel(1, 1), [180, 180, 180]);
+        assert_eq!(pixel(2, 1), [255, 255, 255]);
+        assert_eq!(pixel(3, 3), [200, 0, 0]);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = Style::default().svg(&canvas());
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8">"#));
+        assert!(svg.contains(r##"<rect x="0" y="0" width="4" height="4" fill="#606060"/>"##));
+        assert!(svg.contains(r##"<rect x="4" y="4" width="4" height="4" fill="#f05050"/>"##));
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn polygon_svg() {
+        let polygon = [(0, 0), (4_000_000, 0), (4_000_000, 1_000_000)]
+            .map(Coordinate::from)
+            .into_iter()
+            .collect::<Polygon>();
+        let svg = Style::default().polygon_svg(&polygon, Colour::Green);
+        assert!(svg.contains(r#"width="2048" height="512" viewBox="0 0 4000001 1000001""#));
+        assert!(svg.contains(r#"points="0,0 4000000,0 4000000,1000000""#));
+    }
+}
@@ -0,0 +1,320 @@
+//! A rectangular two-dimensional grid, as used by the puzzles with a map as input.
+
+pub mod image;
+pub mod render;
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::error::ParseError;
+
+pub use common::geometry::{Coordinate, Direction};
+
+/// Position of a cell: `x` is the column, `y` the row, both counted from the top left corner.
+pub type Position = Coordinate<usize>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row. Panics if their number does not match.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size does not match.");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid with one row per line, mapping every character with `cell`. Characters
+    /// without a mapping are reported as errors, which expected `expected` instead.
+    /// Surrounding whitespace of the input and of every line is ignored.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut lines = input.trim().lines().map(str::trim);
+        let width = lines.clone().next().map_or(0, |line| line.chars().count());
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines.by_ref() {
+            for (index, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(ParseError::at(input, &line[index..], expected)),
+                }
+            }
+            height += 1;
+            if cells.len() != width * height {
+                let expected = format!("a row of {width} cells");
+                return Err(ParseError::at(input, line, expected));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Maps any position onto the grid, as if it was repeated infinitely in all directions.
+    pub fn wrap(&self, x: i64, y: i64) -> Position {
+        Position {
+            x: x.rem_euclid(self.width as i64) as usize,
+            y: y.rem_euclid(self.height as i64) as usize,
+        }
+    }
+
+    /// Access to a grid that is repeated infinitely in all directions.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    /// The adjacent position in `direction`, if it is still on the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position
+            .step(direction)
+            .filter(|position| self.contains(*position))
+    }
+
+    /// The horizontally and vertically adjacent positions on the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The horizontally, vertically and diagonally adjacent positions on the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|offset| *offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let x = position.x.checked_add_signed(dx)?;
+                let y = position.y.checked_add_signed(dy)?;
+                Some(Position { x, y })
+            })
+            .filter(|position| self.contains(*position))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Position {
+            x: index % width,
+            y: index / width,
+        })
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, that fulfills `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let index = self.cells.iter().position(predicate)?;
+        Some(Position {
+            x: index % self.width,
+            y: index / self.width,
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|index| {
+                let target = Position {
+                    x: index % width,
+                    y: index / width,
+                };
+                self[source(target)].clone()
+            })
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Mirrors the grid at its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |position| Position {
+            x: position.y,
+            y: position.x,
+        })
+    }
+
+    /// Rotates the grid by 90°, the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |position| Position {
+            x: position.y,
+            y: height - 1 - position.x,
+        })
+    }
+
+    /// Rotates the grid by -90°, the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |position| Position {
+            x: width - 1 - position.y,
+            y: position.x,
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        assert!(self.contains(position), "{position:?} is not on the grid.");
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        assert!(self.contains(position), "{position:?} is not on the grid.");
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10), "a digit").unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(1, 1)], 5);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Grid::parse("12\n345", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(error.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose(), digits("14\n25\n36"));
+        assert_eq!(grid.rotate_clockwise(), digits("41\n52\n63"));
+        assert_eq!(grid.rotate_counterclockwise(), digits("36\n25\n14"));
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789");
+        let values = |positions: Vec<Position>| positions.iter().map(|p| grid[*p]).collect();
+
+        let corner: Vec<u32> = values(grid.neighbours4(Position::new(0, 0)).collect());
+        assert_eq!(corner, [4, 2]);
+        let center: Vec<u32> = values(grid.neighbours8(Position::new(1, 1)).collect());
+        assert_eq!(center, [1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(*grid.get_wrapping(-1, 4), 6);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5, 8]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Drawing of grids as text, with overlays that highlight cells in colour.
+
+use std::fmt::{Display, Write};
+
+use crate::{Grid, Position};
+
+/// The eight standard colours of ANSI terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    pub const ALL: [Colour; 8] = [
+        Colour::Black,
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+        Colour::White,
+    ];
+
+    /// The SGR parameter which selects the colour as foreground.
+    fn ansi_code(self) -> u8 {
+        30 + self as u8
+    }
+
+    /// The symbol which marks the cells of an overlay without one of its own in the drawing
+    /// without colours.
+    pub fn mark(self) -> char {
+        match self {
+            Colour::Black => '.',
+            Colour::Red => '#',
+            Colour::Green => 'O',
+            Colour::Yellow => '*',
+            Colour::Blue => '~',
+            Colour::Magenta => '%',
+            Colour::Cyan => '+',
+            Colour::White => '@',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+    /// The symbol in the drawing without colours.
+    pub plain: char,
+}
+
+/// A symbol and possibly a colour for every cell of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    /// Draws every cell of `grid` as the character returned by `symbol`, without colour.
+    pub fn new<T>(grid: &Grid<T>, mut symbol: impl FnMut(&T) -> char) -> Self {
+        let cells = grid.map(|value| {
+            let symbol = symbol(value);
+            Cell {
+                symbol,
+                colour: None,
+                plain: symbol,
+            }
+        });
+        Canvas { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Colours the cells at `positions`, and replaces their symbols if `symbol` is given.
+    /// Without colours, the cells show `symbol` or else the mark of the colour. Positions beyond
+    /// the canvas are ignored. Later overlays cover earlier ones.
+    pub fn overlay(
+        mut self,
+        positions: impl IntoIterator<Item = Position>,
+        colour: Colour,
+        symbol: Option<char>,
+    ) -> Self {
+        for position in positions {
+            if let Some(cell) = self.cells.get_mut(position) {
+                cell.colour = Some(colour);
+                cell.symbol = symbol.unwrap_or(cell.symbol);
+                cell.plain = symbol.unwrap_or(colour.mark());
+            }
+        }
+        self
+    }
+
+    /// The drawing with ANSI escape sequences for the colours.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if cell.colour != current {
+                    match cell.colour {
+                        Some(colour) => write!(text, "\x1b[{}m", colour.ansi_code()),
+                        None => write!(text, "\x1b[0m"),
+                    }
+                    .expect("Writing to a string cannot fail.");
+                    current = cell.colour;
+                }
+                text.push(cell.symbol);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// The drawing without colours.
+impl Display for Canvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                write!(f, "{}", cell.plain)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlays() {
+        let grid = Grid::parse("#..\n.#.", |c| Some(c == '#'), "").unwrap();
+        let canvas = Canvas::new(&grid, |wall| if *wall { '#' } else { '.' })
+            .overlay(
+                [Position::new(1, 0), Position::new(2, 0)],
+                Colour::Red,
+                Some('*'),
+            )
+            .overlay(
+                [Position::new(2, 0), Position::new(5, 5)],
+                Colour::Blue,
+                None,
+            );
+
+        assert_eq!(canvas.to_string(), "#*~\n.#.\n");
+        assert_eq!(
+            canvas.cells()[Position::new(2, 0)],
+            Cell {
+                symbol: '*',
+                colour: Some(Colour::Blue),
+                plain: '~',
+            }
+        );
+        assert_eq!(canvas.to_ansi(), "#\x1b[31m*\x1b[34m*\x1b[0m\n.#.\n");
+    }
+}
@@ -0,0 +1,322 @@
+//! Intervals of integers, sets of them and their products in several dimensions.
+
+use std::ops::{Range, RangeInclusive};
+
+use common::geometry::Scalar;
+
+/// The integers from `start` up to, but excluding, `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Scalar> Interval<T> {
+    /// An `end` before `start` gives an empty interval.
+    pub fn new(start: T, end: T) -> Self {
+        Interval {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// The integers from `start` up to and including `end`. Panics if `end` is the largest
+    /// value of `T`.
+    pub fn inclusive(start: T, end: T) -> Self {
+        let end = end
+            .checked_add(T::ONE)
+            .expect("Inclusive interval exceeds its type.");
+        Self::new(start, end)
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The first integer behind the interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        self.start.distance(self.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Splits into the integers before `value` and the ones from `value` on.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let value = value.clamp(self.start, self.end);
+        (Self::new(self.start, value), Self::new(value, self.end))
+    }
+
+    /// Moves the interval such that `from` lands on `to`, unless that leaves the range of `T`.
+    pub fn shifted(&self, from: T, to: T) -> Option<Self> {
+        let shift = |value: T| {
+            if to >= from {
+                value.checked_add(to.checked_sub(from)?)
+            } else {
+                value.checked_sub(from.checked_sub(to)?)
+            }
+        };
+        Some(Interval {
+            start: shift(self.start)?,
+            end: shift(self.end)?,
+        })
+    }
+}
+
+impl<T: Scalar> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Scalar> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::inclusive(start, end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Scalar> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if !interval.is_empty() {
+            self.intervals.push(interval);
+            self.coalesce();
+        }
+    }
+
+    /// Restores the order and merges overlapping or touching intervals.
+    fn coalesce(&mut self) {
+        self.intervals.retain(|interval| !interval.is_empty());
+        self.intervals
+            .sort_unstable_by_key(|interval| interval.start);
+        let mut coalesced: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match coalesced.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => coalesced.push(interval),
+            }
+        }
+        self.intervals = coalesced;
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter()
+            .flat_map(|interval| other.iter().map(|other| interval.intersection(other)))
+            .collect()
+    }
+
+    /// The integers of `self` which are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for interval in self.iter() {
+            let mut rest = *interval;
+            for removed in other.iter() {
+                if removed.start >= rest.end {
+                    break;
+                }
+                let (before, _) = rest.split_at(removed.start);
+                intervals.push(before);
+                rest = rest.split_at(removed.end).1;
+            }
+            intervals.push(rest);
+        }
+        intervals.into_iter().collect()
+    }
+
+    /// Moves all integers such that `from` lands on `to`, unless that leaves the range of `T`.
+    pub fn shifted(&self, from: T, to: T) -> Option<Self> {
+        self.iter()
+            .map(|interval| interval.shifted(from, to))
+            .collect()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.iter().any(|interval| interval.contains(value))
+    }
+
+    /// The smallest integer in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(Interval::start)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Scalar> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet {
+            intervals: iter.into_iter().collect(),
+        };
+        set.coalesce();
+        set
+    }
+}
+
+impl<T: Scalar> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+/// The product of one interval per axis: a box in `N` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HyperRectangle<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Scalar, const N: usize> HyperRectangle<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        HyperRectangle { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> u64 {
+        self.axes.iter().map(Interval::len).product()
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(interval, value)| interval.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersection(other);
+        }
+        HyperRectangle { axes }
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Splits along `axis` into the points before `value` and the ones from `value` on.
+    pub fn split_at(&self, axis: usize, value: T) -> (Self, Self) {
+        let (before, after) = self.axes[axis].split_at(value);
+        let (mut first, mut second) = (*self, *self);
+        first.axes[axis] = before;
+        second.axes[axis] = after;
+        (first, second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[Range<i32>]) -> IntervalSet<i32> {
+        intervals.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::from(3..=7u16);
+        assert_eq!(
+            (interval.start(), interval.end(), interval.len()),
+            (3, 8, 5)
+        );
+        assert!(interval.contains(7) && !interval.contains(8));
+        assert!(Interval::new(5, 2).is_empty());
+
+        assert_eq!(interval.intersection(&(6..20).into()), (6..8).into());
+        assert!(!interval.intersects(&(8..20).into()));
+        assert_eq!(interval.split_at(5), ((3..5).into(), (5..8).into()));
+        assert_eq!(interval.split_at(1), ((3..3).into(), (3..8).into()));
+
+        assert_eq!(interval.shifted(3, 0), Some((0..5).into()));
+        assert_eq!(interval.shifted(4, 0), None);
+    }
+
+    #[test]
+    fn sets() {
+        let a = set(&[(0..5), (10..15), (5..7)]);
+        assert_eq!(a, set(&[(0..7), (10..15)]));
+        assert_eq!(a.len(), 12);
+        assert_eq!(a.min(), Some(0));
+
+        let b = set(&[(3..12), (14..20)]);
+        assert_eq!(a.union(&b), IntervalSet::from(Interval::new(0, 20)));
+        assert_eq!(a.intersection(&b), set(&[(3..7), (10..12), (14..15)]));
+        assert_eq!(a.difference(&b), set(&[(0..3), (12..14)]));
+        assert_eq!(b.difference(&a), set(&[(7..10), (15..20)]));
+        assert_eq!(a.shifted(10, 0), Some(set(&[(-10..-3), (0..5)])));
+
+        let mut c = IntervalSet::new();
+        c.insert((1..1).into());
+        assert!(c.is_empty());
+        c.insert((1..4).into());
+        assert!(c.contains(3) && !c.contains(4));
+    }
+
+    #[test]
+    fn hyper_rectangles() {
+        let cube = HyperRectangle::new([(0..4).into(), (0..4).into(), (0..2).into()]);
+        assert_eq!(cube.volume(), 32);
+        assert!(cube.contains([3, 0, 1]));
+
+        let (lower, upper) = cube.split_at(0, 1);
+        assert_eq!((lower.volume(), upper.volume()), (8, 24));
+        assert!(!lower.intersects(&upper));
+
+        let other = HyperRectangle::new([(2..9).into(), (-5..1).into(), (1..5).into()]);
+        assert_eq!(cube.intersection(&other).volume(), 2);
+        assert!(cube.split_at(2, 0).0.is_empty());
+    }
+}
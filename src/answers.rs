@@ -0,0 +1,252 @@
+//! The ledger of confirmed answers, kept in `answers.txt` next to the days.
+//!
+//! Every line holds the day, the part, the name of the input and the answer, separated by
+//! whitespace. Empty lines and lines starting with `#` are ignored. Therefore names of inputs
+//! containing whitespace cannot be recorded.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::error::{self, ParseError};
+
+const HEADER: &str = "# day part input answer\n";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    day: u8,
+    part: u8,
+    input: String,
+}
+
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<Key, String>,
+}
+
+/// Result of comparing an answer with the ledger, or of recording it there.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+    Recorded,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+            Verdict::Recorded => "RECORDED",
+        }
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        match self {
+            Verdict::Fail { expected } => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())?;
+        match self.expected() {
+            Some(expected) => write!(f, " (expected {expected})"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: ParseError,
+    },
+    /// The input has no name under which its answers can be told apart from others.
+    Name {
+        input: String,
+        reason: &'static str,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, error } => write!(f, "Cannot access {}: {error}.", path.display()),
+            Error::Parse { path, error } => write!(f, "Invalid {} at {error}.", path.display()),
+            Error::Name { input, reason } => {
+                write!(f, "Cannot record the answers of {input}: {reason}.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Answers {
+    /// Loads the ledger from `path`. A missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(Error::Io {
+                    path: path.to_path_buf(),
+                    error,
+                })
+            }
+        };
+        let entries = parse(&contents).map_err(|error| Error::Parse {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+        Ok(Answers {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &str) -> Verdict {
+        match self.entries.get(&key(day, part, input)) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Stores `answer` as the confirmed one, replacing a previous entry.
+    pub fn record(&mut self, day: u8, part: u8, input: &str, answer: &str) -> Result<(), Error> {
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return Err(Error::Name {
+                input: format!("{input:?}"),
+                reason: "its name is empty or contains whitespace",
+            });
+        }
+        self.entries
+            .insert(key(day, part, input), answer.to_string());
+        Ok(())
+    }
+
+    /// Writes the ledger back to its file, sorted by day, part and input.
+    pub fn save(&self) -> Result<(), Error> {
+        fs::write(&self.path, self.to_string()).map_err(|error| Error::Io {
+            path: self.path.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{HEADER}")?;
+        for (key, answer) in &self.entries {
+            writeln!(f, "{} {} {} {answer}", key.day, key.part, key.input)?;
+        }
+        Ok(())
+    }
+}
+
+fn key(day: u8, part: u8, input: &str) -> Key {
+    Key {
+        day,
+        part,
+        input: input.to_string(),
+    }
+}
+
+fn parse(contents: &str) -> Result<BTreeMap<Key, String>, ParseError> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split_ascii_whitespace();
+            let mut next = |expected| {
+                fields
+                    .next()
+                    .ok_or_else(|| ParseError::at(contents, &line[line.len()..], expected))
+            };
+            let day = error::number(contents, next("a day")?)?;
+            let part = error::number(contents, next("a part")?)?;
+            let input = next("an input name")?;
+            let answer = next("an answer")?;
+            Ok((key(day, part, input), answer.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn check_and_record() {
+        let contents = "# comment\n\n1 1 input 142\n1 2 demo_input_2 281\n";
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            entries: parse(contents).unwrap(),
+        };
+
+        assert_eq!(answers.check(1, 1, "input", "142"), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 2, "demo_input_2", "280"),
+            Verdict::Fail {
+                expected: "281".to_string()
+            }
+        );
+        assert_eq!(answers.check(1, 2, "input", "281"), Verdict::Unknown);
+
+        answers.record(1, 2, "input", "281").unwrap();
+        answers.record(1, 1, "input", "143").unwrap();
+        assert_eq!(
+            answers.to_string(),
+            "# day part input answer\n1 1 input 143\n1 2 demo_input_2 281\n1 2 input 281\n"
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = env::temp_dir().join(format!("answers-{}.txt", process::id()));
+        let mut answers = Answers::load(&path).unwrap();
+        answers.record(5, 2, "../my input.txt", "46").unwrap_err();
+        answers.record(5, 2, "", "46").unwrap_err();
+        answers.record(5, 2, "../inputs/day_05.txt", "46").unwrap();
+        answers.save().unwrap();
+
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entries, answers.entries);
+        assert_eq!(
+            loaded.check(5, 2, "../inputs/day_05.txt", "46"),
+            Verdict::Pass
+        );
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let error = parse("1 1 input 142\n2 x input 8").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "a number");
+
+        let error = parse("1 1 input").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(error.expected, "an answer");
+    }
+}
@@ -0,0 +1,91 @@
+//! Repeated timing of the parse step and the part solver, summarised as min, median and max.
+
+use std::{fmt::Display, time::Duration};
+
+use serde::Serialize;
+
+/// Minimum, median and maximum of repeated measurements, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Statistics {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl Statistics {
+    /// Summarises the samples, which must not be empty. The median of an even number of
+    /// samples is the upper one of the two middle samples.
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos().try_into().unwrap_or(u64::MAX))
+            .collect::<Vec<u64>>();
+        nanos.sort_unstable();
+        Statistics {
+            min: nanos[0],
+            median: nanos[nanos.len() / 2],
+            max: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>12?} {:>12?} {:>12?}",
+            Duration::from_nanos(self.min),
+            Duration::from_nanos(self.median),
+            Duration::from_nanos(self.max)
+        )
+    }
+}
+
+/// Timings of one part of a day on one input.
+#[derive(Debug, Serialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub runs: usize,
+    #[serde(rename = "parse_ns")]
+    pub parse: Statistics,
+    #[serde(rename = "solve_ns")]
+    pub solve: Statistics,
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {} part {} on {}, {} runs:",
+            self.day, self.part, self.input, self.runs
+        )?;
+        writeln!(f, "{:8} {:>12} {:>12} {:>12}", "", "min", "median", "max")?;
+        writeln!(f, "{:8} {}", "parse", self.parse)?;
+        write!(f, "{:8} {}", "solve", self.solve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let samples = [5, 1, 4, 2].map(Duration::from_micros);
+        let statistics = Statistics::new(&samples);
+        assert_eq!(
+            statistics,
+            Statistics {
+                min: 1000,
+                median: 4000,
+                max: 5000
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&statistics).unwrap(),
+            r#"{"min":1000,"median":4000,"max":5000}"#
+        );
+    }
+}
@@ -0,0 +1,561 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use answers::{Answers, Verdict};
+use bench::{Benchmark, Statistics};
+use clap::{ArgGroup, Parser};
+use common::{
+    answer::Answer,
+    input::Source,
+    random::{Random, SizeError},
+    solution::{self, Failure, Registry, Run, Solver},
+};
+use grid::image::Style;
+use picture::{FrameFormat, FrameWriter, PaletteName, Picture};
+use serde::Serialize;
+use summary::{Row, Summary};
+use tracing::info_span;
+
+mod answers;
+mod bench;
+mod picture;
+mod summary;
+mod trace;
+
+/// Runs the solver of one part of an Advent of Code 2023 puzzle.
+#[derive(Parser)]
+#[command(name = "aoc")]
+#[command(group(ArgGroup::new("pictures").args(["export", "frames"]).multiple(true)))]
+struct Args {
+    /// Day of the puzzle
+    #[arg(required_unless_present = "all",
+          value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part of the puzzle
+    #[arg(required_unless_present = "all",
+          value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input of the puzzle: the name of a data file of the day (e.g. "demo_input2"),
+    /// a path to a file or "-" for stdin
+    #[arg(default_value = "input")]
+    input: Source,
+
+    /// Generate a random input from this seed instead of reading one
+    #[arg(long, value_name = "SEED", conflicts_with = "input")]
+    generate: Option<u64>,
+
+    /// Size of the generated input, like the number of rows, maps or hailstones
+    #[arg(long, default_value_t = 10, requires = "generate")]
+    size: usize,
+
+    /// Also write the generated input to this file
+    #[arg(long, value_name = "PATH", requires = "generate")]
+    save_input: Option<PathBuf>,
+
+    /// Name of the solver to run, for days with alternative solutions (default: the first one)
+    #[arg(long, value_name = "NAME")]
+    solver: Option<String>,
+
+    /// Run both parts of every day with every solver in parallel on the data file of this name
+    /// and print a summary table
+    #[arg(long, value_name = "INPUT", num_args = 0..=1, default_missing_value = "input",
+          conflicts_with_all = ["day", "part", "generate", "solver", "assumptions", "record", "bench",
+                                "render", "export", "frames"])]
+    all: Option<String>,
+
+    /// Check whether the input has the properties the solver relies on, instead of solving
+    #[arg(long, conflicts_with_all = ["record", "bench"])]
+    assumptions: bool,
+
+    /// Print the events of the solvers to stderr, as LEVEL for the days being run or as
+    /// DAY=LEVEL for one day, separated by commas (e.g. "debug" or "12=debug,20=trace")
+    #[arg(long, value_name = "FILTER")]
+    trace: Option<trace::Filter>,
+
+    /// Record the answer as confirmed in answers.txt instead of checking it
+    #[arg(long)]
+    record: bool,
+
+    /// Time parsing and solving over this many runs and print min, median and max
+    #[arg(long, value_name = "RUNS", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Also write the benchmark as JSON to this file
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    bench_report: Option<PathBuf>,
+
+    /// Print the result as JSON instead of text
+    #[arg(long)]
+    json: bool,
+
+    /// Draw the map of the day with the overlays of the part before the answer
+    #[arg(long, conflicts_with = "json")]
+    render: bool,
+
+    /// Draw the map without ANSI colours
+    #[arg(long, requires = "render")]
+    no_colour: bool,
+
+    /// Write the picture of the day with the overlays of the part to this .ppm or .svg file
+    #[arg(long, value_name = "PATH")]
+    export: Option<PathBuf>,
+
+    /// Write every step of the simulation of the day as a numbered frame into this directory
+    #[arg(long, value_name = "DIRECTORY")]
+    frames: Option<PathBuf>,
+
+    /// File format of the frames
+    #[arg(long, value_enum, default_value_t = FrameFormat::Text, requires = "frames")]
+    frame_format: FrameFormat,
+
+    /// Side length in pixels of a map cell, or of a polygon unit, in exported pictures and frames
+    #[arg(long, value_name = "PIXELS", default_value_t = 4, requires = "pictures",
+          value_parser = clap::value_parser!(u16).range(1..))]
+    cell_size: u16,
+
+    /// Colours of exported pictures and frames
+    #[arg(long, value_enum, default_value_t = PaletteName::Dark, requires = "pictures")]
+    palette: PaletteName,
+}
+
+impl Args {
+    fn day(&self) -> u8 {
+        self.day.expect("The day is required without --all.")
+    }
+
+    fn part(&self) -> u8 {
+        self.part.expect("The part is required without --all.")
+    }
+
+    /// The name of the input in the ledger and in reports.
+    fn input_name(&self) -> String {
+        match self.generate {
+            Some(seed) => format!("generated-{seed}-{}", self.size),
+            None => self.input.to_string(),
+        }
+    }
+}
+
+/// The solvers of all days, with the alternatives some days have.
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    day_01::register(&mut registry);
+    day_02::register(&mut registry);
+    day_03::register(&mut registry);
+    day_04::register(&mut registry);
+    day_05::register(&mut registry);
+    day_06::register(&mut registry);
+    day_07::register(&mut registry);
+    day_08::register(&mut registry);
+    day_09::register(&mut registry);
+    day_10::register(&mut registry);
+    day_11::register(&mut registry);
+    day_12::register(&mut registry);
+    day_13::register(&mut registry);
+    day_14::register(&mut registry);
+    day_15::register(&mut registry);
+    day_16::register(&mut registry);
+    day_17::register(&mut registry);
+    day_18::register(&mut registry);
+    day_19::register(&mut registry);
+    day_20::register(&mut registry);
+    day_21::register(&mut registry);
+    day_22::register(&mut registry);
+    day_23::register(&mut registry);
+    day_24::register(&mut registry);
+    day_25::register(&mut registry);
+    registry
+}
+
+/// A random input of the given size for the days which have a generator.
+fn generate(day: u8, seed: u64, size: usize) -> Option<Result<String, SizeError>> {
+    let random = &mut Random::new(seed);
+    let input = match day {
+        5 => Ok(day_05::generator::generate(random, size)),
+        10 => Ok(day_10::generator::generate(random, size)),
+        12 => Ok(day_12::generator::generate(random, size)),
+        19 => Ok(day_19::generator::generate(random, size)),
+        20 => day_20::generator::generate(random, size),
+        24 => day_24::generator::generate(random, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Some(filter) = &args.trace {
+        let days = match args.day {
+            Some(day) => vec![day],
+            None => (1..=25).collect(),
+        };
+        filter.init(&days);
+    }
+    if let Some(input) = &args.all {
+        return run_all(&args, input);
+    }
+    // Overflows are reported as failures of the solver, without the panic message before.
+    solution::quiet_overflows();
+
+    let day_directory =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("day_{:02}", args.day()));
+    let input = match args.generate {
+        Some(seed) => match generate(args.day(), seed, args.size) {
+            Some(Ok(input)) => input,
+            Some(Err(error)) => {
+                eprintln!("{error}.");
+                return ExitCode::FAILURE;
+            }
+            None => {
+                eprintln!("Day {} has no input generator.", args.day());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => match args.input.read(&day_directory) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if let Some(path) = &args.save_input {
+        if let Err(error) = fs::write(path, &input) {
+            eprintln!("Cannot write {}: {error}.", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if args.render || args.export.is_some() {
+        match picture::draw(args.day(), args.part(), &input) {
+            Some(Ok(picture)) => {
+                if !show(&args, &picture) {
+                    return ExitCode::FAILURE;
+                }
+            }
+            Some(Err(error)) => {
+                eprintln!("Invalid input {} at {error}.", args.input);
+                return ExitCode::FAILURE;
+            }
+            None => {
+                eprintln!("Day {} has no picture to draw.", args.day());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Some(directory) = &args.frames {
+        if !record_frames(&args, directory, &input) {
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let registry = registry();
+    let Some(entry) = registry.get(args.day(), args.solver.as_deref()) else {
+        let names = registry
+            .day(args.day())
+            .map(|entry| entry.name)
+            .collect::<Vec<_>>();
+        eprintln!(
+            "Day {} has no solver called {}. Its solvers are: {}.",
+            args.day(),
+            args.solver.as_deref().unwrap_or_default(),
+            names.join(", ")
+        );
+        return ExitCode::FAILURE;
+    };
+    if args.assumptions {
+        return check_assumptions(&args, entry.solver.as_ref(), &input);
+    }
+    let span = info_span!("solve", day = args.day(), part = args.part());
+    match span.in_scope(|| entry.solver.run(args.part(), &input)) {
+        Some(Ok(run)) => report(&args, entry.solver.as_ref(), &input, &run),
+        Some(Err(Failure::Parse(error))) => {
+            eprintln!("Invalid input {} at {error}.", args.input_name());
+            ExitCode::FAILURE
+        }
+        Some(Err(Failure::Assumptions(failed))) => {
+            eprintln!(
+                "Input {} does not fit the solver of day {} part {}:",
+                args.input_name(),
+                args.day(),
+                args.part()
+            );
+            for assumption in failed {
+                eprintln!("  {}", assumption.description);
+            }
+            ExitCode::FAILURE
+        }
+        Some(Err(Failure::Overflow(message))) => {
+            eprintln!(
+                "An integer overflowed solving day {} part {} for input {}: {message}.",
+                args.day(),
+                args.part(),
+                args.input_name()
+            );
+            ExitCode::FAILURE
+        }
+        None => {
+            eprintln!("Day {} has no part {}.", args.day(), args.part());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints whether each assumption of the solver holds for the input. Fails if any does not.
+fn check_assumptions(args: &Args, solver: &dyn Solver, input: &str) -> ExitCode {
+    match solver.check(args.part(), input) {
+        Ok(assumptions) if assumptions.is_empty() => {
+            println!("The solver makes no assumptions about the input.");
+            ExitCode::SUCCESS
+        }
+        Ok(assumptions) => {
+            assumptions
+                .iter()
+                .for_each(|assumption| println!("{assumption}"));
+            if assumptions.iter().all(|assumption| assumption.holds) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("Invalid input {} at {error}.", args.input_name());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs every solver on `input` of its day and prints the summary, or the rows as JSON.
+fn run_all(args: &Args, input: &str) -> ExitCode {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let answers = match Answers::load(&root.join("answers.txt")) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let start = Instant::now();
+    let registry = registry();
+    let rows = summary::run(registry.iter(), &root, input, &answers);
+    if args.json {
+        println!("{}", to_json(&rows));
+    } else {
+        let summary = Summary {
+            rows: &rows,
+            elapsed: start.elapsed(),
+        };
+        println!("{summary}");
+    }
+
+    if rows.iter().all(Row::is_success) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints the picture in the terminal and exports it, as requested. False if either failed.
+fn show(args: &Args, picture: &Picture) -> bool {
+    if args.render {
+        match picture {
+            Picture::Map(canvas) if args.no_colour => print!("{canvas}"),
+            Picture::Map(canvas) => print!("{}", canvas.to_ansi()),
+            Picture::Polygon(_) => {
+                eprintln!(
+                    "Day {} draws a polygon, which can only be exported.",
+                    args.day()
+                );
+                return false;
+            }
+        }
+    }
+    if let Some(path) = &args.export {
+        if let Err(error) = picture::export(picture, path, &style(args)) {
+            eprintln!("Cannot export {}: {error}.", path.display());
+            return false;
+        }
+    }
+    true
+}
+
+/// Writes the frames of the simulation of the day into `directory`. False if that failed.
+fn record_frames(args: &Args, directory: &Path, input: &str) -> bool {
+    let style = style(args);
+    let mut writer = match FrameWriter::new(directory, args.frame_format, &style) {
+        Ok(writer) => writer,
+        Err(error) => {
+            eprintln!("Cannot create {}: {error}.", directory.display());
+            return false;
+        }
+    };
+    match picture::frames(args.day(), args.part(), input, &mut |canvas| {
+        writer.write(&canvas)
+    }) {
+        Some(Ok(())) => (),
+        Some(Err(error)) => {
+            eprintln!("Invalid input {} at {error}.", args.input);
+            return false;
+        }
+        None => {
+            eprintln!("Day {} has no simulation to record.", args.day());
+            return false;
+        }
+    }
+    match writer.finish() {
+        Ok(count) => {
+            eprintln!("Wrote {count} frames to {}.", directory.display());
+            true
+        }
+        Err(error) => {
+            eprintln!("Cannot write frames to {}: {error}.", directory.display());
+            false
+        }
+    }
+}
+
+fn style(args: &Args) -> Style {
+    Style {
+        cell_size: args.cell_size.into(),
+        palette: args.palette.into(),
+    }
+}
+
+/// The result of a run as printed in JSON mode.
+#[derive(Serialize)]
+struct Output<'a> {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: &'a Answer,
+    elapsed_ns: u64,
+    verdict: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    benchmark: Option<&'a Benchmark>,
+}
+
+/// Prints the answer with its verdict from the ledger, and the benchmark if requested.
+fn report(args: &Args, solver: &dyn Solver, input: &str, run: &Run) -> ExitCode {
+    let mut success = true;
+    let answer = run.answer.to_string();
+    let verdict = check(args, &answer).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        success = false;
+        Verdict::Unknown
+    });
+    let benchmark = args
+        .bench
+        .and_then(|runs| match benchmark(args, solver, input, runs, run) {
+            Ok(benchmark) => Some(benchmark),
+            Err(error) => {
+                eprintln!("{error}");
+                success = false;
+                None
+            }
+        });
+
+    if args.json {
+        let output = Output {
+            day: args.day(),
+            part: args.part(),
+            input: args.input_name(),
+            answer: &run.answer,
+            elapsed_ns: nanos(run.parse + run.solve),
+            verdict: verdict.name(),
+            expected: verdict.expected(),
+            benchmark: benchmark.as_ref(),
+        };
+        println!("{}", to_json(&output));
+    } else {
+        println!("{answer} {verdict}");
+        if let Some(benchmark) = &benchmark {
+            println!("{benchmark}");
+        }
+    }
+
+    if let (Some(path), Some(benchmark)) = (&args.bench_report, &benchmark) {
+        if let Err(error) = fs::write(path, to_json(benchmark) + "\n") {
+            eprintln!("Cannot write {}: {error}.", path.display());
+            success = false;
+        }
+    }
+
+    if success && !matches!(verdict, Verdict::Fail { .. }) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Compares the answer with the ledger, or records it there.
+fn check(args: &Args, answer: &str) -> Result<Verdict, answers::Error> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.txt");
+    let input = args.input_name();
+    let mut answers = Answers::load(&path)?;
+    if args.record {
+        // Piped inputs all share the name stdin, so their answers would overwrite each other.
+        if args.input == Source::Stdin {
+            return Err(answers::Error::Name {
+                input,
+                reason: "save the input to a file first",
+            });
+        }
+        answers.record(args.day(), args.part(), &input, answer)?;
+        answers.save()?;
+        Ok(Verdict::Recorded)
+    } else {
+        Ok(answers.check(args.day(), args.part(), &input, answer))
+    }
+}
+
+/// Repeats `run` until there are `runs` of them and summarises the timings. Fails if one of the
+/// repeated runs does.
+fn benchmark(
+    args: &Args,
+    solver: &dyn Solver,
+    input: &str,
+    runs: u32,
+    run: &Run,
+) -> Result<Benchmark, String> {
+    let mut parse_times = vec![run.parse];
+    let mut solve_times = vec![run.solve];
+    for count in 2..=runs {
+        match solver.run(args.part(), input) {
+            Some(Ok(run)) => {
+                parse_times.push(run.parse);
+                solve_times.push(run.solve);
+            }
+            Some(Err(failure)) => {
+                return Err(format!("Run {count} of the benchmark failed. {failure}"))
+            }
+            None => return Err(format!("Run {count} of the benchmark gave no answer.")),
+        }
+    }
+
+    Ok(Benchmark {
+        day: args.day(),
+        part: args.part(),
+        input: args.input_name(),
+        runs: parse_times.len(),
+        parse: Statistics::new(&parse_times),
+        solve: Statistics::new(&solve_times),
+    })
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).expect("Output can always be serialized.")
+}
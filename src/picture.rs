@@ -0,0 +1,170 @@
+//! The pictures of the days, drawn in the terminal or exported as image files.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use common::{error::ParseError, polygon::Polygon};
+use grid::{
+    image::{Palette, Style},
+    render::{Canvas, Colour},
+};
+
+/// What a day draws: a map of cells, or the outline of a shape too large for cells.
+pub enum Picture {
+    Map(Canvas),
+    Polygon(Polygon),
+}
+
+/// Draws the picture of the day with the overlays of the part, for the days which have one.
+pub fn draw(day: u8, part: u8, input: &str) -> Option<Result<Picture, ParseError>> {
+    let map = |canvas: Result<Canvas, ParseError>| Some(canvas.map(Picture::Map));
+    match day {
+        10 => map(day_10::parse(input).map(|map| day_10::render(&map, part))),
+        14 => map(day_14::parse(input).map(|platform| day_14::render(&platform, part))),
+        16 => map(day_16::parse(input).map(|contraption| day_16::render(&contraption, part))),
+        17 => map(day_17::parse(input).map(|city_map| day_17::render(&city_map, part))),
+        18 => Some(
+            day_18::parse(input)
+                .map(|instructions| Picture::Polygon(day_18::lagoon(&instructions, part))),
+        ),
+        21 => map(day_21::parse(input).map(|map| day_21::render(&map, part))),
+        22 => map(day_22::parse(input).map(|cubes| day_22::render(&cubes, part))),
+        23 => map(day_23::parse(input).map(|map| day_23::render(&map, part))),
+        _ => None,
+    }
+}
+
+/// Draws every step of the simulation of the day and part as a frame, for the days which
+/// simulate step by step.
+pub fn frames(
+    day: u8,
+    part: u8,
+    input: &str,
+    frame: &mut impl FnMut(Canvas),
+) -> Option<Result<(), ParseError>> {
+    let frames = match day {
+        14 => day_14::parse(input).map(|platform| day_14::frames(&platform, part, frame)),
+        16 => day_16::parse(input).map(|contraption| day_16::frames(&contraption, part, frame)),
+        21 => day_21::parse(input).map(|map| day_21::frames(&map, part, frame)),
+        22 => day_22::parse(input).map(|cubes| day_22::frames(&cubes, part, frame)),
+        _ => return None,
+    };
+    Some(frames)
+}
+
+/// The file formats of frames.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum FrameFormat {
+    Text,
+    Ppm,
+}
+
+/// Writes frames as a numbered sequence of files into a directory, and stops at the first error.
+pub struct FrameWriter<'a> {
+    directory: &'a Path,
+    format: FrameFormat,
+    style: &'a Style,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> FrameWriter<'a> {
+    /// Creates the directory if it does not exist yet.
+    pub fn new(directory: &'a Path, format: FrameFormat, style: &'a Style) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        Ok(FrameWriter {
+            directory,
+            format,
+            style,
+            count: 0,
+            error: None,
+        })
+    }
+
+    fn path(&self) -> PathBuf {
+        let extension = match self.format {
+            FrameFormat::Text => "txt",
+            FrameFormat::Ppm => "ppm",
+        };
+        self.directory
+            .join(format!("frame_{:05}.{extension}", self.count))
+    }
+
+    pub fn write(&mut self, canvas: &Canvas) {
+        if self.error.is_some() {
+            return;
+        }
+        let contents = match self.format {
+            FrameFormat::Text => canvas.to_string().into_bytes(),
+            FrameFormat::Ppm => self.style.ppm(canvas),
+        };
+        match fs::write(self.path(), contents) {
+            Ok(()) => self.count += 1,
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    /// The number of frames written, or the first error.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.count),
+        }
+    }
+}
+
+/// The colour schemes which can be chosen on the command line.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PaletteName {
+    Dark,
+    Light,
+}
+
+impl From<PaletteName> for Palette {
+    fn from(name: PaletteName) -> Self {
+        match name {
+            PaletteName::Dark => Palette::dark(),
+            PaletteName::Light => Palette::light(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    /// The file extension names no supported format.
+    Format,
+    /// Polygons have no cells to draw as pixels.
+    PolygonAsPpm,
+    Io(io::Error),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Format => write!(f, "the file name must end in .ppm or .svg"),
+            ExportError::PolygonAsPpm => write!(f, "polygons can only be exported as .svg"),
+            ExportError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// Writes the picture to `path` in the format given by its extension, PPM or SVG.
+pub fn export(picture: &Picture, path: &Path, style: &Style) -> Result<(), ExportError> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    let contents = match (extension.as_deref(), picture) {
+        (Some("ppm"), Picture::Map(canvas)) => style.ppm(canvas),
+        (Some("ppm"), Picture::Polygon(_)) => return Err(ExportError::PolygonAsPpm),
+        (Some("svg"), Picture::Map(canvas)) => style.svg(canvas).into_bytes(),
+        (Some("svg"), Picture::Polygon(polygon)) => {
+            style.polygon_svg(polygon, Colour::Green).into_bytes()
+        }
+        _ => return Err(ExportError::Format),
+    };
+    fs::write(path, contents).map_err(ExportError::Io)
+}
@@ -0,0 +1,311 @@
+//! Runs every registered solver on both parts in parallel, isolating panics, and summarises
+//! the outcomes in a table.
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{answer::Answer, input::Source, solution::Entry};
+use rayon::prelude::*;
+use serde::Serialize;
+use tracing::info_span;
+
+use crate::answers::{Answers, Verdict};
+
+/// How running one part of a day ended.
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        verdict: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expected: Option<String>,
+    },
+    /// The input could not be read or parsed.
+    Error {
+        message: String,
+    },
+    Panic {
+        message: String,
+    },
+}
+
+/// The outcome of one part of a day with one of its solvers.
+#[derive(Debug, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub solver: &'static str,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    pub elapsed_ns: u64,
+}
+
+impl Row {
+    /// True unless the answer is wrong, or there is none.
+    pub fn is_success(&self) -> bool {
+        matches!(&self.outcome, Outcome::Solved { verdict, .. } if *verdict != "FAIL")
+    }
+}
+
+thread_local! {
+    /// Where the last panic on this thread happened, taken by the job which caught it.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs both parts of every solver on the data file `input` of its day, checked against
+/// `answers`. The rows are sorted by day and part, and the solvers of a day keep the order in
+/// which they were registered.
+pub fn run<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    root: &Path,
+    input: &str,
+    answers: &Answers,
+) -> Vec<Row> {
+    let entries = entries.collect::<Vec<_>>();
+    let source = Source::Data(input.to_string());
+    let inputs = entries
+        .iter()
+        .map(|entry| {
+            let directory = root.join(format!("day_{:02}", entry.day));
+            (
+                entry.day,
+                source.read(&directory).map_err(|error| error.to_string()),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let jobs = entries
+        .iter()
+        .flat_map(|entry| [1, 2].map(|part| (*entry, part)))
+        .collect::<Vec<_>>();
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(ToString::to_string);
+        PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+    }));
+    let mut rows = jobs
+        .into_par_iter()
+        .filter_map(|(entry, part)| run_part(entry, part, &inputs[&entry.day], input, answers))
+        .collect::<Vec<_>>();
+    panic::set_hook(hook);
+
+    rows.sort_by_key(|row| (row.day, row.part));
+    rows
+}
+
+/// `None` if the solver has no such part.
+fn run_part(
+    entry: &Entry,
+    part: u8,
+    text: &Result<String, String>,
+    input: &str,
+    answers: &Answers,
+) -> Option<Row> {
+    let row = |outcome, elapsed: Duration| Row {
+        day: entry.day,
+        part,
+        solver: entry.name,
+        outcome,
+        elapsed_ns: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+    };
+    let text = match text {
+        Ok(text) => text,
+        Err(message) => {
+            let message = message.clone();
+            return Some(row(Outcome::Error { message }, Duration::ZERO));
+        }
+    };
+
+    let start = Instant::now();
+    let span = info_span!("solve", day = entry.day, part, solver = entry.name);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        span.in_scope(|| entry.solver.run(part, text))
+    }));
+    let outcome = match result {
+        Ok(None) => return None,
+        Ok(Some(Ok(run))) => {
+            let verdict = answers.check(entry.day, part, input, &run.answer.to_string());
+            let outcome = Outcome::Solved {
+                answer: run.answer,
+                verdict: verdict.name(),
+                expected: verdict.expected().map(str::to_string),
+            };
+            return Some(row(outcome, run.parse + run.solve));
+        }
+        Ok(Some(Err(failure))) => Outcome::Error {
+            message: failure.to_string(),
+        },
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown panic".to_string());
+            let location = PANIC_LOCATION.with(|last| last.borrow_mut().take());
+            let message = match location {
+                Some(location) => format!("{message} ({location})"),
+                None => message,
+            };
+            Outcome::Panic { message }
+        }
+    };
+    Some(row(outcome, start.elapsed()))
+}
+
+/// The rows as a table, with a line counting the outcomes.
+pub struct Summary<'a> {
+    pub rows: &'a [Row],
+    pub elapsed: Duration,
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                let (answer, verdict) = match &row.outcome {
+                    Outcome::Solved {
+                        answer,
+                        verdict,
+                        expected,
+                    } => match expected {
+                        Some(expected) => (answer.to_string(), format!("{verdict} ({expected})")),
+                        None => (answer.to_string(), verdict.to_string()),
+                    },
+                    Outcome::Error { message } => (String::new(), format!("ERROR {message}")),
+                    Outcome::Panic { message } => (String::new(), format!("PANIC {message}")),
+                };
+                let elapsed = format!("{:?}", Duration::from_nanos(row.elapsed_ns));
+                (row, answer, verdict, elapsed)
+            })
+            .collect::<Vec<_>>();
+        let solver_width = cells
+            .iter()
+            .map(|(row, ..)| row.solver.len())
+            .chain(["solver".len()])
+            .max()
+            .unwrap_or_default();
+        let answer_width = cells
+            .iter()
+            .map(|(_, answer, ..)| answer.len())
+            .chain(["answer".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:>3} {:>4} {:solver_width$} {:>answer_width$} {:>12} verdict",
+            "day", "part", "solver", "answer", "time"
+        )?;
+        for (row, answer, verdict, elapsed) in &cells {
+            writeln!(
+                f,
+                "{:>3} {:>4} {:solver_width$} {answer:>answer_width$} {elapsed:>12} {verdict}",
+                row.day, row.part, row.solver
+            )?;
+        }
+
+        let count = |status: &str| {
+            self.rows
+                .iter()
+                .filter(|row| match &row.outcome {
+                    Outcome::Solved { verdict, .. } => *verdict == status,
+                    Outcome::Error { .. } => status == "ERROR",
+                    Outcome::Panic { .. } => status == "PANIC",
+                })
+                .count()
+        };
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown, {} errors, {} panics in {:?}",
+            count(Verdict::Pass.name()),
+            count("FAIL"),
+            count(Verdict::Unknown.name()),
+            count("ERROR"),
+            count("PANIC"),
+            self.elapsed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{error::ParseError, solution::Registry, solution::Solution, total::Overflow};
+
+    use super::*;
+
+    /// Answers the length of the input, or panics for part 2.
+    struct Length;
+
+    impl Solution for Length {
+        type Parsed<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<&'a str, ParseError> {
+            Ok(input)
+        }
+
+        fn part1(&self, input: &&str) -> Result<Answer, Overflow> {
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, _: &&str) -> Option<Result<Answer, Overflow>> {
+            panic!("Part 2 is broken.")
+        }
+    }
+
+    #[test]
+    fn panics_are_isolated() {
+        let mut registry = Registry::new();
+        registry.register(2, "length", Length);
+        registry.register(1, "length", Length);
+        registry.register(1, "other", Length);
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let answers = Answers::load(Path::new("does-not-exist.txt")).unwrap();
+
+        let rows = run(registry.iter(), root, "demo_input", &answers);
+        let order = rows
+            .iter()
+            .map(|row| (row.day, row.part, row.solver))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            [
+                (1, 1, "length"),
+                (1, 1, "other"),
+                (1, 2, "length"),
+                (1, 2, "other"),
+                (2, 1, "length"),
+                (2, 2, "length"),
+            ]
+        );
+        assert!(matches!(
+            &rows[0].outcome,
+            Outcome::Solved {
+                verdict: "UNKNOWN",
+                ..
+            }
+        ));
+        let Outcome::Panic { message } = &rows[2].outcome else {
+            panic!("Part 2 did not panic.");
+        };
+        assert!(message.starts_with("Part 2 is broken. (src/summary.rs:"));
+        assert!(!rows[2].is_success());
+
+        let rows = run(registry.iter(), root, "no_such_input", &answers);
+        assert!(matches!(&rows[0].outcome, Outcome::Error { .. }));
+        let table = Summary {
+            rows: &rows,
+            elapsed: Duration::ZERO,
+        }
+        .to_string();
+        assert!(table.ends_with("0 passed, 0 failed, 0 unknown, 6 errors, 0 panics in 0ns"));
+    }
+}
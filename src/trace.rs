@@ -0,0 +1,111 @@
+//! Turns on the events which the days emit with `tracing`, per day and level, printed to
+//! stderr.
+
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal},
+    str::FromStr,
+};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{filter::Targets, prelude::*};
+
+/// Levels of events to show, as `LEVEL` for the days being run, or `DAY=LEVEL` for one day,
+/// separated by commas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    levels: Vec<(Option<u8>, LevelFilter)>,
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let levels = s
+            .split(',')
+            .map(|directive| {
+                let (day, level) = match directive.split_once('=') {
+                    Some((day, level)) => {
+                        let day = day
+                            .parse::<u8>()
+                            .ok()
+                            .filter(|day| (1..=25).contains(day))
+                            .ok_or_else(|| format!("{day} is no day from 1 to 25"))?;
+                        (Some(day), level)
+                    }
+                    None => (None, directive),
+                };
+                let level = level.parse::<LevelFilter>().map_err(|_| {
+                    format!("{level} is none of off, error, warn, info, debug and trace")
+                })?;
+                Ok((day, level))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Filter { levels })
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let directives = self
+            .levels
+            .iter()
+            .map(|(day, level)| match day {
+                Some(day) => format!("{day}={level}"),
+                None => level.to_string(),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", directives.join(","))
+    }
+}
+
+impl Filter {
+    /// The targets of the days with their levels. A level without a day applies to `days`.
+    fn targets(&self, days: &[u8]) -> Targets {
+        self.levels
+            .iter()
+            .flat_map(|(day, level)| {
+                let days = match day {
+                    Some(day) => vec![*day],
+                    None => days.to_vec(),
+                };
+                days.into_iter()
+                    .map(move |day| (format!("day_{day:02}"), *level))
+            })
+            // The spans of the runner tell which day and part an event belongs to.
+            .chain([("aoc".to_string(), LevelFilter::INFO)])
+            .collect()
+    }
+
+    /// Prints the events of `days` at the chosen levels to stderr from now on.
+    pub fn init(&self, days: &[u8]) {
+        let layer = tracing_subscriber::fmt::layer()
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .with_filter(self.targets(days));
+        tracing_subscriber::registry().with(layer).init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::Level;
+
+    use super::*;
+
+    #[test]
+    fn filters() {
+        let filter = "debug,20=trace".parse::<Filter>().unwrap();
+        assert_eq!(filter.to_string(), "debug,20=trace");
+
+        let targets = filter.targets(&[12]);
+        assert!(targets.would_enable("day_12", &Level::DEBUG));
+        assert!(!targets.would_enable("day_12", &Level::TRACE));
+        assert!(targets.would_enable("day_20", &Level::TRACE));
+        assert!(!targets.would_enable("day_13", &Level::ERROR));
+        assert!(targets.would_enable("aoc", &Level::INFO));
+
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("26=debug".parse::<Filter>().is_err());
+    }
+}